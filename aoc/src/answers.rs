@@ -0,0 +1,137 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs, io,
+    path::Path,
+};
+
+use common::Part;
+
+/// Confirmed answers for real puzzle inputs, stored as `day<TAB>part<TAB>input<TAB>answer` lines,
+/// where `input` is the input's path relative to the workspace root.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Answers(BTreeMap<(u8, u8, String), String>);
+
+impl Answers {
+    pub(crate) fn load(path: &Path) -> io::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => return Err(error),
+        };
+
+        Self::parse(&contents).map_err(|line| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("malformed answers line {}", line),
+            )
+        })
+    }
+
+    /// Returns the 1-based number of the first malformed line on failure.
+    fn parse(contents: &str) -> Result<Self, usize> {
+        let mut answers = BTreeMap::new();
+
+        for (index, line) in contents.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let entry = (|| {
+                let mut fields = line.splitn(4, '\t');
+                let day = fields.next()?.parse().ok()?;
+                let part = fields
+                    .next()?
+                    .parse()
+                    .ok()
+                    .filter(|part| (1..=2).contains(part))?;
+                let input = fields.next()?.to_string();
+                let answer = fields.next()?.to_string();
+
+                Some(((day, part, input), answer))
+            })();
+
+            let (key, answer) = entry.ok_or(index + 1)?;
+            answers.insert(key, answer);
+        }
+
+        Ok(Self(answers))
+    }
+
+    pub(crate) fn save(&self, path: &Path) -> io::Result<()> {
+        let mut contents = String::from("# day\tpart\tinput\tanswer\n");
+
+        for ((day, part, input), answer) in &self.0 {
+            contents.push_str(&format!("{}\t{}\t{}\t{}\n", day, part, input, answer));
+        }
+
+        fs::write(path, contents)
+    }
+
+    pub(crate) fn get(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        self.0
+            .get(&(day, part.number(), input.to_string()))
+            .map(String::as_str)
+    }
+
+    pub(crate) fn insert(&mut self, day: u8, part: Part, input: &str, answer: String) {
+        self.0
+            .insert((day, part.number(), input.to_string()), answer);
+    }
+
+    /// Inputs with a recorded answer for `day`, in order, each listed once whichever parts it
+    /// has answers for.
+    pub(crate) fn inputs(&self, day: u8) -> Vec<&str> {
+        self.0
+            .keys()
+            .filter(|(answer_day, _, _)| *answer_day == day)
+            .map(|(_, _, input)| input.as_str())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_works() {
+        let answers = Answers::parse(
+            "# day\tpart\tinput\tanswer\n\
+             6\t1\tday-6/src/aoc-input/input.txt\t4752\n\
+             17\t1\tday-17/src/aoc-input/input.txt\t7,3,0\n\
+             17\t1\tinputs/other.txt\t1,2\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            answers.get(6, Part::One, "day-6/src/aoc-input/input.txt"),
+            Some("4752")
+        );
+        assert_eq!(
+            answers.get(6, Part::Two, "day-6/src/aoc-input/input.txt"),
+            None
+        );
+        assert_eq!(
+            answers.inputs(17),
+            vec!["day-17/src/aoc-input/input.txt", "inputs/other.txt"]
+        );
+
+        assert_eq!(Answers::parse("6\t3\tinput.txt\t1\n"), Err(1));
+        assert_eq!(Answers::parse("\n6\t1\tinput.txt\n"), Err(2));
+    }
+
+    #[test]
+    fn inputs_works() {
+        let answers = Answers::parse(
+            "6\t1\tinputs/a.txt\t1\n\
+             6\t2\tinputs/a.txt\t2\n\
+             6\t1\tinputs/b.txt\t3\n\
+             6\t2\tinputs/b.txt\t4\n",
+        )
+        .unwrap();
+
+        assert_eq!(answers.inputs(6), vec!["inputs/a.txt", "inputs/b.txt"]);
+    }
+}
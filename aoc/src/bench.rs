@@ -0,0 +1,256 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+    time::Duration,
+};
+
+use common::{bench::Stats, Day, Part, PartSelection};
+
+use crate::read_input;
+
+/// What is being timed within a day.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) enum Stage {
+    Parse,
+    Part(u8),
+}
+
+impl Stage {
+    fn of(part: Part) -> Self {
+        match part {
+            Part::One => Stage::Part(1),
+            Part::Two => Stage::Part(2),
+        }
+    }
+
+    fn key(&self) -> String {
+        match self {
+            Stage::Parse => String::from("parse"),
+            Stage::Part(part) => format!("part{}", part),
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "parse" => Some(Stage::Parse),
+            "part1" => Some(Stage::Part(1)),
+            "part2" => Some(Stage::Part(2)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+/// Median timings from a previous benchmark run, stored as `day<TAB>stage<TAB>nanoseconds`
+/// lines.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Baseline(BTreeMap<(u8, Stage), Duration>);
+
+impl Baseline {
+    pub(crate) fn load(path: &Path) -> io::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => return Err(error),
+        };
+
+        Self::parse(&contents).map_err(|line| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("malformed baseline line {}", line),
+            )
+        })
+    }
+
+    /// Returns the 1-based number of the first malformed line on failure.
+    fn parse(contents: &str) -> Result<Self, usize> {
+        let mut medians = BTreeMap::new();
+
+        for (index, line) in contents.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let entry = (|| {
+                let mut fields = line.split('\t');
+                let day = fields.next()?.parse().ok()?;
+                let stage = Stage::from_key(fields.next()?)?;
+                let nanos = fields.next()?.parse().ok()?;
+
+                fields
+                    .next()
+                    .is_none()
+                    .then_some(((day, stage), Duration::from_nanos(nanos)))
+            })();
+
+            let (key, median) = entry.ok_or(index + 1)?;
+            medians.insert(key, median);
+        }
+
+        Ok(Self(medians))
+    }
+
+    pub(crate) fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    fn get(&self, day: u8, stage: Stage) -> Option<Duration> {
+        self.0.get(&(day, stage)).copied()
+    }
+
+    fn insert(&mut self, day: u8, stage: Stage, median: Duration) {
+        self.0.insert((day, stage), median);
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day\tstage\tmedian nanoseconds")?;
+
+        for ((day, stage), median) in &self.0 {
+            writeln!(f, "{}\t{}\t{}", day, stage.key(), median.as_nanos())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// How much slower `current` is than `baseline`, as a percentage.
+fn change_percentage(baseline: Duration, current: Duration) -> f64 {
+    (current.as_secs_f64() / baseline.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0) * 100.0
+}
+
+pub(crate) struct BenchOptions<'a> {
+    pub(crate) part: PartSelection,
+    pub(crate) iterations: usize,
+    pub(crate) threshold: f64,
+    pub(crate) compare_to: &'a Baseline,
+}
+
+pub(crate) enum BenchOutcome {
+    Measured { regressions: usize },
+    Failed,
+}
+
+/// Benchmarks one day, printing its timings and recording the medians in `results`.
+pub(crate) fn bench_day(day: &Day, options: &BenchOptions, results: &mut Baseline) -> BenchOutcome {
+    let Some(input) = read_input(day) else {
+        return BenchOutcome::Failed;
+    };
+
+    let (parts, skipped): (Vec<_>, Vec<_>) = options
+        .part
+        .parts()
+        .into_iter()
+        .partition(|part| !day.interactive_parts.contains(part));
+
+    let bench = match (day.bench)(&input, &parts, options.iterations) {
+        Ok(bench) => bench,
+        Err(error) => {
+            eprintln!("Day {}: could not parse input: {}", day.number, error);
+            return BenchOutcome::Failed;
+        }
+    };
+
+    println!("Day {} ({} iterations)", day.number, bench.iterations);
+
+    let mut regressions = 0;
+
+    let stages = [(Stage::Parse, bench.parse)].into_iter().chain(
+        bench
+            .parts
+            .iter()
+            .map(|&(part, stats)| (Stage::of(part), stats)),
+    );
+
+    for (stage, stats) in stages {
+        let comparison = options
+            .compare_to
+            .get(day.number, stage)
+            .map(|baseline| {
+                let change = change_percentage(baseline, stats.median);
+                let regressed = change > options.threshold;
+
+                if regressed {
+                    regressions += 1;
+                }
+
+                format!(
+                    "  (baseline {:.2?}, {:+.1}%{})",
+                    baseline,
+                    change,
+                    if regressed { ", REGRESSION" } else { "" }
+                )
+            })
+            .unwrap_or_default();
+
+        println!(
+            "  {:<8}{}{}",
+            stage.to_string(),
+            format_stats(&stats),
+            comparison
+        );
+
+        results.insert(day.number, stage, stats.median);
+    }
+
+    for part in skipped {
+        println!("  {:<8}skipped (interactive)", Stage::of(part).to_string());
+    }
+
+    BenchOutcome::Measured { regressions }
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!(
+        "mean {:>10.2?}  median {:>10.2?}  min {:>10.2?}",
+        stats.mean, stats.median, stats.min
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn baseline_round_trip_works() {
+        let mut baseline = Baseline::default();
+        baseline.insert(6, Stage::Part(2), Duration::from_millis(1500));
+        baseline.insert(6, Stage::Parse, Duration::from_micros(12));
+        baseline.insert(12, Stage::Part(1), Duration::from_nanos(42));
+
+        let contents = baseline.to_string();
+
+        assert_eq!(
+            contents,
+            "# day\tstage\tmedian nanoseconds\n6\tparse\t12000\n6\tpart2\t1500000000\n12\tpart1\t42\n"
+        );
+        assert_eq!(Baseline::parse(&contents), Ok(baseline));
+        assert_eq!(Baseline::parse("6\tparse\t12\n6\tpart3\t1\n"), Err(2));
+    }
+
+    #[test]
+    fn change_percentage_works() {
+        let change = |baseline, current| {
+            change_percentage(
+                Duration::from_millis(baseline),
+                Duration::from_millis(current),
+            )
+            .round()
+        };
+
+        assert_eq!(change(100, 150), 50.0);
+        assert_eq!(change(100, 75), -25.0);
+        assert_eq!(change(100, 100), 0.0);
+    }
+}
@@ -0,0 +1,188 @@
+use std::{
+    fmt::{self, Display},
+    fs::File,
+    io::BufWriter,
+    ops::Range,
+    path::PathBuf,
+    str::FromStr,
+};
+
+use visualizer::{write_frame_directory, write_gif, ExportError, Frames, Image, ImageFormat};
+
+use crate::visualize::FramesVisitor;
+
+/// Frames written to an animation or a frame directory: `12..40`, `12..=40` or `12..`.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct FrameRange {
+    start: usize,
+    end: Option<usize>,
+}
+
+impl FrameRange {
+    fn resolve(&self, frame_count: usize) -> Result<Range<usize>, FrameError> {
+        let end = self.end.unwrap_or(frame_count);
+
+        if end > frame_count || self.start >= end {
+            return Err(FrameError::OutOfRange {
+                requested: format!("frames {}", self),
+                frame_count,
+            });
+        }
+
+        Ok(self.start..end)
+    }
+}
+
+impl Display for FrameRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.end {
+            Some(end) => write!(f, "{}..{}", self.start, end),
+            None => write!(f, "{}..", self.start),
+        }
+    }
+}
+
+impl FromStr for FrameRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |number: &str| {
+            number
+                .parse::<usize>()
+                .map_err(|_| format!("`{}` is not a frame number", number))
+        };
+
+        let (start, end) = if let Some((start, end)) = s.split_once("..=") {
+            let end = parse(end)?
+                .checked_add(1)
+                .ok_or_else(|| format!("`{}` is past the last possible frame", end))?;
+            (parse(start)?, Some(end))
+        } else if let Some((start, end)) = s.split_once("..") {
+            let end = if end.is_empty() {
+                None
+            } else {
+                Some(parse(end)?)
+            };
+            (parse(start)?, end)
+        } else {
+            return Err(format!("`{}` is not a range like 12..40", s));
+        };
+
+        Ok(Self { start, end })
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum FrameError {
+    OutOfRange {
+        requested: String,
+        frame_count: usize,
+    },
+    Export(ExportError),
+}
+
+impl Display for FrameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FrameError::OutOfRange {
+                requested,
+                frame_count,
+            } => write!(
+                f,
+                "{} is outside of the simulation's {} frames",
+                requested, frame_count
+            ),
+            FrameError::Export(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl From<ExportError> for FrameError {
+    fn from(error: ExportError) -> Self {
+        FrameError::Export(error)
+    }
+}
+
+impl From<std::io::Error> for FrameError {
+    fn from(error: std::io::Error) -> Self {
+        FrameError::Export(ExportError::Io(error))
+    }
+}
+
+/// Writes one frame as an image, a range of frames as a GIF, or a range of frames into a
+/// directory of PNGs, depending on the output's extension.
+pub(crate) struct Export {
+    pub(crate) output: PathBuf,
+    pub(crate) frame: Option<usize>,
+    pub(crate) frames: FrameRange,
+    pub(crate) scale: usize,
+    pub(crate) delay: u16,
+}
+
+impl FramesVisitor for Export {
+    type Output = Result<(), FrameError>;
+
+    fn visit<F: Frames>(self, mut frames: F) -> Self::Output {
+        let frame_count = frames.frame_count();
+
+        if self
+            .output
+            .extension()
+            .is_some_and(|extension| extension == "gif")
+        {
+            let range = self.frames.resolve(frame_count)?;
+            let file = File::create(&self.output)?;
+
+            return Ok(write_gif(&mut frames, range, self.scale, self.delay, file)?);
+        }
+
+        if let Some(format) = ImageFormat::from_path(&self.output) {
+            let index = self.frame.unwrap_or(frame_count.saturating_sub(1));
+            if index >= frame_count {
+                return Err(FrameError::OutOfRange {
+                    requested: format!("frame {}", index),
+                    frame_count,
+                });
+            }
+
+            let image = Image::from_grid(&frames.frame(index), self.scale);
+            return Ok(image.write(format, BufWriter::new(File::create(&self.output)?))?);
+        }
+
+        let range = self.frames.resolve(frame_count)?;
+
+        Ok(write_frame_directory(
+            &mut frames,
+            range,
+            self.scale,
+            ImageFormat::Png,
+            &self.output,
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn frame_range_works() {
+        let range = FrameRange::from_str("3..").unwrap();
+        assert_eq!(range.resolve(10).ok(), Some(3..10));
+
+        let range = FrameRange::from_str("3..=5").unwrap();
+        assert_eq!(range.resolve(10).ok(), Some(3..6));
+
+        let range = FrameRange::from_str("3..11").unwrap();
+        assert!(range.resolve(10).is_err());
+
+        assert!(FrameRange::from_str("3").is_err());
+        assert!(FrameRange::from_str("a..3").is_err());
+        assert_eq!(
+            FrameRange::from_str("0..=18446744073709551615").err(),
+            Some(String::from(
+                "`18446744073709551615` is past the last possible frame"
+            ))
+        );
+    }
+}
@@ -0,0 +1,394 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+pub(crate) const SESSION_ENV_VAR: &str = "AOC_SESSION";
+
+/// Where the session token is read from when `AOC_SESSION` is not set, relative to the workspace
+/// root.
+pub(crate) const SESSION_FILE: &str = "aoc-session.txt";
+
+pub(crate) const BASE_URL: &str = "https://adventofcode.com";
+
+const YEAR: u16 = 2024;
+
+const USER_AGENT: &str = concat!("aoc-2024-runner/", env!("CARGO_PKG_VERSION"));
+
+/// The least time to leave between requests to the site, even across runs.
+pub(crate) const REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A response from an [`HttpBackend`], whatever its status.
+#[derive(Debug, PartialEq)]
+pub(crate) struct HttpResponse {
+    pub(crate) status: u16,
+    pub(crate) body: String,
+}
+
+/// Makes the requests for [`Fetcher`], so that it can be pointed at something other than the real
+/// site.
+pub(crate) trait HttpBackend {
+    /// Sends a GET request for `url` with the session cookie, failing only when no response
+    /// arrives.
+    fn get(&self, url: &str, session: &str) -> Result<HttpResponse, String>;
+}
+
+pub(crate) struct UreqBackend {
+    agent: ureq::Agent,
+}
+
+impl Default for UreqBackend {
+    fn default() -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+}
+
+impl HttpBackend for UreqBackend {
+    fn get(&self, url: &str, session: &str) -> Result<HttpResponse, String> {
+        let response = match self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", session))
+            .call()
+        {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(error) => return Err(error.to_string()),
+        };
+
+        Ok(HttpResponse {
+            status: response.status(),
+            body: response.into_string().map_err(|error| error.to_string())?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum FetchError {
+    Request(String),
+    SessionRejected,
+    NotAvailable(u8),
+    Status(u16),
+    Io(io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Request(error) => write!(f, "request failed: {}", error),
+            FetchError::SessionRejected => write!(
+                f,
+                "the session token was rejected, it may have expired or been copied wrongly"
+            ),
+            FetchError::NotAvailable(day) => {
+                write!(f, "day {}'s input is not available yet", day)
+            }
+            FetchError::Status(status) => write!(f, "unexpected response status {}", status),
+            FetchError::Io(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<io::Error> for FetchError {
+    fn from(error: io::Error) -> Self {
+        FetchError::Io(error)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Fetched {
+    /// The input was downloaded and written, this many bytes of it.
+    Saved(usize),
+    /// There was already an input, so nothing was requested.
+    AlreadyPresent,
+}
+
+/// Downloads puzzle inputs, keeping to a [`RateLimit`] and never replacing an input that is
+/// already there.
+pub(crate) struct Fetcher<B> {
+    pub(crate) backend: B,
+    pub(crate) base_url: String,
+    pub(crate) session: String,
+    pub(crate) rate_limit: RateLimit,
+}
+
+impl<B: HttpBackend> Fetcher<B> {
+    /// Downloads the day's input to `path`, unless there already is a file there.
+    pub(crate) fn fetch(&self, day: u8, path: &Path) -> Result<Fetched, FetchError> {
+        if path.exists() {
+            return Ok(Fetched::AlreadyPresent);
+        }
+
+        self.rate_limit.wait()?;
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+        let response = self
+            .backend
+            .get(&url, &self.session)
+            .map_err(FetchError::Request)?;
+
+        match response.status {
+            200 => {}
+            // The site answers a missing or stale session with 400 and a locked puzzle with 404.
+            400 | 401 => return Err(FetchError::SessionRejected),
+            404 => return Err(FetchError::NotAvailable(day)),
+            status => return Err(FetchError::Status(status)),
+        }
+
+        // Still refuse to overwrite, in case the input appeared while waiting.
+        let mut file = OpenOptions::new().write(true).create_new(true).open(path)?;
+        file.write_all(response.body.as_bytes())?;
+
+        Ok(Fetched::Saved(response.body.len()))
+    }
+}
+
+/// Keeps requests at least `interval` apart by recording in a file when the last one was made, so
+/// that running the command again straight away still waits.
+pub(crate) struct RateLimit {
+    pub(crate) path: PathBuf,
+    pub(crate) interval: Duration,
+}
+
+impl RateLimit {
+    /// Sleeps until `interval` has passed since the last request, then records a new one.
+    pub(crate) fn wait(&self) -> io::Result<()> {
+        let now = SystemTime::now();
+
+        if let Some(last) = self.last_request() {
+            // A clock that went backwards counts as having just made a request.
+            let elapsed = now.duration_since(last).unwrap_or(Duration::ZERO);
+            thread::sleep(self.interval.saturating_sub(elapsed));
+        }
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        // Saved to the nanosecond, as rounding down would let the next wait fall short.
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        fs::write(&self.path, nanos.to_string())
+    }
+
+    fn last_request(&self) -> Option<SystemTime> {
+        let nanos = fs::read_to_string(&self.path).ok()?.trim().parse().ok()?;
+        Some(UNIX_EPOCH + Duration::from_nanos(nanos))
+    }
+}
+
+/// The session token from the `AOC_SESSION` environment variable, or else from `path`.
+pub(crate) fn read_session(path: &Path) -> io::Result<String> {
+    let session = match env::var(SESSION_ENV_VAR) {
+        Ok(session) => session,
+        Err(_) => fs::read_to_string(path)?,
+    };
+
+    // Copied from the browser's cookies, the value may come with its name.
+    let session = session.trim();
+    let session = session.strip_prefix("session=").unwrap_or(session);
+
+    if session.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "the session token is empty",
+        ));
+    }
+
+    Ok(session.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, process, time::Instant};
+
+    use super::*;
+
+    /// A fresh directory for one test, removed when dropped.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, process::id()));
+            let _ = fs::remove_dir_all(&path);
+            fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Answers every request with the same response, counting them.
+    struct FakeBackend {
+        response: (u16, &'static str),
+        requests: Cell<usize>,
+    }
+
+    impl HttpBackend for FakeBackend {
+        fn get(&self, _url: &str, _session: &str) -> Result<HttpResponse, String> {
+            self.requests.set(self.requests.get() + 1);
+            Ok(HttpResponse {
+                status: self.response.0,
+                body: self.response.1.to_string(),
+            })
+        }
+    }
+
+    fn fetcher<B>(backend: B, base_url: &str, dir: &TempDir, interval: Duration) -> Fetcher<B> {
+        Fetcher {
+            backend,
+            base_url: base_url.to_string(),
+            session: String::from("53616c7465645f5f"),
+            rate_limit: RateLimit {
+                path: dir.0.join("last-request"),
+                interval,
+            },
+        }
+    }
+
+    #[test]
+    fn fetch_works() {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr());
+
+        let requests = thread::spawn(move || {
+            let request = server.recv().unwrap();
+            let seen = (
+                request.url().to_string(),
+                request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string()),
+            );
+            request
+                .respond(tiny_http::Response::from_string("3   4\n4   3\n"))
+                .unwrap();
+
+            let request = server.recv().unwrap();
+            request
+                .respond(
+                    tiny_http::Response::from_string("Puzzle inputs differ by user.")
+                        .with_status_code(400),
+                )
+                .unwrap();
+
+            seen
+        });
+
+        let dir = TempDir::new("works");
+        let fetcher = fetcher(UreqBackend::default(), &base_url, &dir, Duration::ZERO);
+
+        let path = dir.0.join("input.txt");
+        assert_eq!(fetcher.fetch(1, &path).unwrap(), Fetched::Saved(12));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n4   3\n");
+
+        let other_path = dir.0.join("other-input.txt");
+        assert!(matches!(
+            fetcher.fetch(2, &other_path),
+            Err(FetchError::SessionRejected)
+        ));
+        assert!(!other_path.exists());
+
+        assert_eq!(
+            requests.join().unwrap(),
+            (
+                String::from("/2024/day/1/input"),
+                Some(String::from("session=53616c7465645f5f"))
+            )
+        );
+    }
+
+    #[test]
+    fn fetch_never_overwrites() {
+        let dir = TempDir::new("never-overwrites");
+        let backend = FakeBackend {
+            response: (200, "new input"),
+            requests: Cell::new(0),
+        };
+        let fetcher = fetcher(backend, BASE_URL, &dir, Duration::ZERO);
+
+        let path = dir.0.join("input.txt");
+        fs::write(&path, "old input").unwrap();
+
+        assert_eq!(fetcher.fetch(1, &path).unwrap(), Fetched::AlreadyPresent);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "old input");
+        assert_eq!(fetcher.backend.requests.get(), 0);
+
+        let fetcher = Fetcher {
+            backend: FakeBackend {
+                response: (
+                    404,
+                    "Please don't repeatedly request this endpoint before it unlocks!",
+                ),
+                requests: Cell::new(0),
+            },
+            ..fetcher
+        };
+
+        assert!(matches!(
+            fetcher.fetch(25, &dir.0.join("locked.txt")),
+            Err(FetchError::NotAvailable(25))
+        ));
+        assert!(!dir.0.join("locked.txt").exists());
+    }
+
+    #[test]
+    fn rate_limit_works() {
+        let dir = TempDir::new("rate-limit");
+        let interval = Duration::from_millis(200);
+        let rate_limit = RateLimit {
+            path: dir.0.join("nested/last-request"),
+            interval,
+        };
+
+        let start = Instant::now();
+        rate_limit.wait().unwrap();
+        assert!(start.elapsed() < interval);
+
+        rate_limit.wait().unwrap();
+        assert!(start.elapsed() >= interval);
+
+        // The file carries the limit over to a new run.
+        let start = Instant::now();
+        RateLimit {
+            path: rate_limit.path.clone(),
+            interval,
+        }
+        .wait()
+        .unwrap();
+        assert!(start.elapsed() >= interval / 2);
+    }
+
+    #[test]
+    fn read_session_works() {
+        let dir = TempDir::new("session");
+        let path = dir.0.join("aoc-session.txt");
+        fs::write(&path, "session=53616c7465645f5f\n").unwrap();
+
+        if env::var_os(SESSION_ENV_VAR).is_none() {
+            assert_eq!(read_session(&path).unwrap(), "53616c7465645f5f");
+            assert!(read_session(&dir.0.join("missing.txt")).is_err());
+
+            fs::write(&path, "\n").unwrap();
+            assert!(read_session(&path).is_err());
+        }
+    }
+}
@@ -0,0 +1,724 @@
+use std::collections::HashSet;
+
+use common::Solution;
+use rand::{rngs::ChaCha8Rng, seq::SliceRandom, RngExt, SeedableRng};
+
+/// Produces random puzzle inputs for one day, shaped like the real input so the solutions can be
+/// timed on inputs larger than it.
+pub(crate) struct Generator {
+    pub(crate) day: u8,
+    /// What the size of a generated input counts.
+    pub(crate) unit: &'static str,
+    /// Roughly the size of the real puzzle input.
+    pub(crate) default_size: usize,
+    /// The smallest size the input can keep the real input's shape at.
+    pub(crate) minimum_size: usize,
+    generate: fn(&mut ChaCha8Rng, usize) -> String,
+}
+
+impl Generator {
+    /// Generates an input of the given size, which must be at least the minimum size. The same
+    /// seed and size always give the same input.
+    pub(crate) fn generate(&self, seed: u64, size: usize) -> String {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+        (self.generate)(&mut rng, size)
+    }
+}
+
+pub(crate) const GENERATORS: [Generator; 17] = [
+    Generator {
+        day: 1,
+        unit: "pairs of location IDs",
+        default_size: 1000,
+        minimum_size: 1,
+        generate: location_id_lists,
+    },
+    Generator {
+        day: 2,
+        unit: "reports",
+        default_size: 1000,
+        minimum_size: 1,
+        generate: reports,
+    },
+    Generator {
+        day: 3,
+        unit: "instructions",
+        default_size: 800,
+        minimum_size: 1,
+        generate: corrupted_memory,
+    },
+    Generator {
+        day: 4,
+        unit: "letters along each side",
+        default_size: 140,
+        minimum_size: 1,
+        generate: word_search,
+    },
+    Generator {
+        day: 5,
+        unit: "updates",
+        default_size: 200,
+        minimum_size: 1,
+        generate: print_queue,
+    },
+    Generator {
+        day: 6,
+        unit: "tiles along each side",
+        default_size: 130,
+        minimum_size: 1,
+        generate: lab_map,
+    },
+    Generator {
+        day: 7,
+        unit: "equations",
+        default_size: 850,
+        minimum_size: 1,
+        generate: calibration_equations,
+    },
+    Generator {
+        day: 8,
+        unit: "tiles along each side",
+        default_size: 50,
+        minimum_size: 1,
+        generate: antenna_map,
+    },
+    Generator {
+        day: 9,
+        unit: "digits",
+        default_size: 19999,
+        minimum_size: 1,
+        generate: disk_map,
+    },
+    Generator {
+        day: 10,
+        unit: "positions along each side",
+        default_size: 50,
+        minimum_size: 1,
+        generate: topographic_map,
+    },
+    Generator {
+        day: 11,
+        unit: "stones",
+        default_size: 8,
+        minimum_size: 1,
+        generate: stones,
+    },
+    Generator {
+        day: 12,
+        unit: "plots along each side",
+        default_size: 140,
+        minimum_size: 1,
+        generate: garden,
+    },
+    Generator {
+        day: 13,
+        unit: "claw machines",
+        default_size: 320,
+        minimum_size: 1,
+        generate: claw_machines,
+    },
+    Generator {
+        day: 14,
+        unit: "robots",
+        default_size: 500,
+        minimum_size: 1,
+        generate: robots,
+    },
+    Generator {
+        day: 15,
+        unit: "tiles along each side of the warehouse",
+        default_size: 50,
+        minimum_size: 3,
+        generate: warehouse,
+    },
+    Generator {
+        day: 16,
+        unit: "tiles along each side",
+        default_size: 141,
+        minimum_size: 5,
+        generate: maze,
+    },
+    Generator {
+        day: 17,
+        unit: "instructions",
+        default_size: 8,
+        minimum_size: 3,
+        generate: program,
+    },
+];
+
+pub(crate) fn find_generator(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.day == day)
+}
+
+/// Joins the rows of a grid into lines.
+fn render(rows: &[Vec<char>]) -> String {
+    rows.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+fn location_id_lists(rng: &mut ChaCha8Rng, pairs: usize) -> String {
+    let left = (0..pairs)
+        .map(|_| rng.random_range(10000..100000))
+        .collect::<Vec<usize>>();
+
+    left.iter()
+        .map(|left_id| {
+            // Reuse left IDs often enough for the similarity score to count them.
+            let right_id = if rng.random_bool(0.5) {
+                left[rng.random_range(0..left.len())]
+            } else {
+                rng.random_range(10000..100000)
+            };
+
+            format!("{}   {}\n", left_id, right_id)
+        })
+        .collect()
+}
+
+fn reports(rng: &mut ChaCha8Rng, count: usize) -> String {
+    (0..count)
+        .map(|_| {
+            let len = rng.random_range(5..=8);
+            let direction = if rng.random_bool(0.5) { 1 } else { -1 };
+            let mut level: i64 = rng.random_range(30..=70);
+            let mut levels = vec![level];
+
+            for _ in 1..len {
+                // Mostly safe steps, with the odd one that is too large, flat or backwards.
+                let step = if rng.random_bool(0.9) {
+                    direction * rng.random_range(1..=3)
+                } else {
+                    [0, 4 * direction, -direction][rng.random_range(0..3)]
+                };
+
+                level += step;
+                levels.push(level);
+            }
+
+            let levels = levels.iter().map(i64::to_string).collect::<Vec<_>>();
+
+            levels.join(" ") + "\n"
+        })
+        .collect()
+}
+
+fn corrupted_memory(rng: &mut ChaCha8Rng, instructions: usize) -> String {
+    const JUNK: &[u8] = b"!@#$%^&*()[]{}<>,;:'?/+- _mulxdont";
+
+    let mut memory = String::new();
+
+    for i in 0..instructions {
+        for _ in 0..rng.random_range(0..8) {
+            memory.push(JUNK[rng.random_range(0..JUNK.len())] as char);
+        }
+
+        let a = rng.random_range(1..1000);
+        let b = rng.random_range(1..1000);
+
+        match rng.random_range(0..10) {
+            0 => memory.push_str("do()"),
+            1 => memory.push_str("don't()"),
+            2 => memory.push_str(&format!("mul({}*{})", a, b)),
+            3 => memory.push_str(&format!("mul ( {},{} )", a, b)),
+            _ => memory.push_str(&format!("mul({},{})", a, b)),
+        }
+
+        if (i + 1) % 60 == 0 {
+            memory.push('\n');
+        }
+    }
+
+    memory + "\n"
+}
+
+fn word_search(rng: &mut ChaCha8Rng, side: usize) -> String {
+    const LETTERS: [char; 4] = ['X', 'M', 'A', 'S'];
+
+    let rows = (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| LETTERS[rng.random_range(0..LETTERS.len())])
+                .collect()
+        })
+        .collect::<Vec<_>>();
+
+    render(&rows)
+}
+
+fn print_queue(rng: &mut ChaCha8Rng, updates: usize) -> String {
+    // Like the real input, the rules give an order between every pair of pages.
+    let mut pages = (10..100).collect::<Vec<usize>>();
+    pages.shuffle(rng);
+    pages.truncate(49);
+
+    let mut rules = Vec::new();
+    for (i, before) in pages.iter().enumerate() {
+        for after in &pages[i + 1..] {
+            rules.push(format!("{}|{}\n", before, after));
+        }
+    }
+    rules.shuffle(rng);
+
+    let mut input = rules.concat() + "\n";
+
+    for _ in 0..updates {
+        // An odd number of pages, so the update has a middle page.
+        let len = rng.random_range(2..=11) * 2 + 1;
+
+        let mut indices = (0..pages.len()).collect::<Vec<_>>();
+        indices.shuffle(rng);
+        indices.truncate(len);
+
+        if rng.random_bool(0.5) {
+            indices.sort_unstable();
+        }
+
+        let update = indices
+            .iter()
+            .map(|&index| pages[index].to_string())
+            .collect::<Vec<_>>();
+
+        input += &(update.join(",") + "\n");
+    }
+
+    input
+}
+
+fn lab_map(rng: &mut ChaCha8Rng, side: usize) -> String {
+    loop {
+        let mut rows = (0..side)
+            .map(|_| {
+                (0..side)
+                    .map(|_| if rng.random_bool(0.05) { '#' } else { '.' })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let (x, y) = (rng.random_range(0..side), rng.random_range(0..side));
+        rows[y][x] = '^';
+
+        // Part one assumes the guard leaves the map, so try another map if they would not.
+        if guard_leaves(&rows, x, y) {
+            return render(&rows);
+        }
+    }
+}
+
+/// Whether the guard starting at `(x, y)` facing up walks off the map, rather than in a loop.
+fn guard_leaves(rows: &[Vec<char>], mut x: usize, mut y: usize) -> bool {
+    let (mut dx, mut dy) = (0, -1);
+    let mut visited = HashSet::new();
+
+    while visited.insert((x, y, dx, dy)) {
+        let (Some(next_x), Some(next_y)) = (x.checked_add_signed(dx), y.checked_add_signed(dy))
+        else {
+            return true;
+        };
+
+        match rows.get(next_y).and_then(|row| row.get(next_x)) {
+            None => return true,
+            Some('#') => (dx, dy) = (-dy, dx),
+            Some(_) => (x, y) = (next_x, next_y),
+        }
+    }
+
+    false
+}
+
+fn calibration_equations(rng: &mut ChaCha8Rng, count: usize) -> String {
+    // Concatenating every part is the largest result any operators can give, so keeping that
+    // within 18 digits means no evaluation can overflow.
+    const MAX_DIGITS: u32 = 18;
+
+    (0..count)
+        .map(|_| {
+            let len = rng.random_range(2..=12);
+            let mut digits_left = MAX_DIGITS;
+
+            let parts = (0..len)
+                .map(|i| {
+                    let max_digits = digits_left.saturating_sub(len - i - 1).clamp(1, 3);
+                    let digits = rng.random_range(1..=max_digits);
+                    digits_left -= digits;
+
+                    rng.random_range(10usize.pow(digits - 1)..10usize.pow(digits))
+                })
+                .collect::<Vec<_>>();
+
+            let result = parts
+                .iter()
+                .copied()
+                .reduce(|acc, part| match rng.random_range(0..3) {
+                    0 => acc + part,
+                    1 => acc * part,
+                    _ => acc * 10usize.pow(part.ilog10() + 1) + part,
+                })
+                .unwrap();
+
+            // About half of the equations can't be made true.
+            let result = if rng.random_bool(0.5) {
+                result
+            } else {
+                result + rng.random_range(1..100)
+            };
+
+            let parts = parts.iter().map(usize::to_string).collect::<Vec<_>>();
+
+            format!("{}: {}\n", result, parts.join(" "))
+        })
+        .collect()
+}
+
+fn antenna_map(rng: &mut ChaCha8Rng, side: usize) -> String {
+    const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    let rows = (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| {
+                    if rng.random_bool(0.04) {
+                        FREQUENCIES[rng.random_range(0..FREQUENCIES.len())] as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect::<Vec<_>>();
+
+    render(&rows)
+}
+
+fn disk_map(rng: &mut ChaCha8Rng, digits: usize) -> String {
+    // An odd number of digits, so the map ends with a file like the real input.
+    let digits = digits | 1;
+
+    let disk_map = (0..digits)
+        .map(|i| {
+            let len = if i % 2 == 0 {
+                rng.random_range(1..=9)
+            } else {
+                rng.random_range(0..=9)
+            };
+
+            char::from_digit(len, 10).unwrap()
+        })
+        .collect::<String>();
+
+    disk_map + "\n"
+}
+
+fn topographic_map(rng: &mut ChaCha8Rng, side: usize) -> String {
+    // Hills fall away by one in every direction from a peak of 9, so trails climb from the 0s at
+    // their foot, with some noise to break a few of them.
+    let peaks = (0..(side * side / 150).max(1))
+        .map(|_| (rng.random_range(0..side), rng.random_range(0..side)))
+        .collect::<Vec<_>>();
+
+    let rows = (0..side)
+        .map(|y| {
+            (0..side)
+                .map(|x| {
+                    let distance = peaks
+                        .iter()
+                        .map(|&(peak_x, peak_y)| x.abs_diff(peak_x) + y.abs_diff(peak_y))
+                        .min()
+                        .unwrap();
+
+                    let height = if rng.random_bool(0.05) {
+                        rng.random_range(0..=9)
+                    } else {
+                        9usize.saturating_sub(distance)
+                    };
+
+                    char::from_digit(height as u32, 10).unwrap()
+                })
+                .collect()
+        })
+        .collect::<Vec<_>>();
+
+    render(&rows)
+}
+
+fn stones(rng: &mut ChaCha8Rng, count: usize) -> String {
+    let stones = (0..count)
+        .map(|_| rng.random_range(0..10_000_000usize).to_string())
+        .collect::<Vec<_>>();
+
+    stones.join(" ") + "\n"
+}
+
+fn garden(rng: &mut ChaCha8Rng, side: usize) -> String {
+    let mut rows = vec![vec!['A'; side]; side];
+
+    // Most plots copy a neighbour's plant, so plants grow in regions rather than single plots.
+    for y in 0..side {
+        for x in 0..side {
+            rows[y][x] = match (x, y) {
+                (0, 0) => random_plant(rng),
+                _ if !rng.random_bool(0.85) => random_plant(rng),
+                (0, _) => rows[y - 1][x],
+                (_, 0) => rows[y][x - 1],
+                _ if rng.random_bool(0.5) => rows[y - 1][x],
+                _ => rows[y][x - 1],
+            };
+        }
+    }
+
+    render(&rows)
+}
+
+fn random_plant(rng: &mut ChaCha8Rng) -> char {
+    rng.random_range(b'A'..=b'Z') as char
+}
+
+fn claw_machines(rng: &mut ChaCha8Rng, count: usize) -> String {
+    let machines = (0..count)
+        .map(|_| {
+            // Buttons that move the claw in the same direction have no single cheapest way to
+            // win, which the real input avoids.
+            let buttons = loop {
+                let buttons = Buttons {
+                    a_x: rng.random_range(10..100),
+                    a_y: rng.random_range(10..100),
+                    b_x: rng.random_range(10..100),
+                    b_y: rng.random_range(10..100),
+                };
+
+                if buttons.determinant() != 0 {
+                    break buttons;
+                }
+            };
+
+            // A third of the prizes can be won before the correction, a third after it, and the
+            // rest not at all.
+            let won_prize = match rng.random_range(0..3) {
+                0 => {
+                    let (a, b) = (rng.random_range(1..=100), rng.random_range(1..=100));
+                    Some((
+                        a * buttons.a_x + b * buttons.b_x,
+                        a * buttons.a_y + b * buttons.b_y,
+                    ))
+                }
+                1 => buttons.prize_won_after_correction(rng),
+                _ => None,
+            };
+
+            let (prize_x, prize_y) = won_prize
+                .unwrap_or_else(|| (rng.random_range(1000..20000), rng.random_range(1000..20000)));
+
+            format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                buttons.a_x, buttons.a_y, buttons.b_x, buttons.b_y, prize_x, prize_y
+            )
+        })
+        .collect::<Vec<_>>();
+
+    machines.join("\n")
+}
+
+struct Buttons {
+    a_x: i64,
+    a_y: i64,
+    b_x: i64,
+    b_y: i64,
+}
+
+impl Buttons {
+    fn determinant(&self) -> i64 {
+        self.a_x * self.b_y - self.b_x * self.a_y
+    }
+
+    /// A prize position that some whole number of presses reaches once it is corrected, if the
+    /// buttons can reach that far out along the diagonal at all.
+    fn prize_won_after_correction(&self, rng: &mut ChaCha8Rng) -> Option<(i64, i64)> {
+        let correction = day_13::PRIZE_POSITION_CORRECTION as i64;
+
+        let target_x = (correction + rng.random_range(1200..19800)) as f64;
+        let target_y = (correction + rng.random_range(1200..19800)) as f64;
+        let determinant = self.determinant() as f64;
+
+        // The presses closest to the target, which then moves to wherever they actually reach.
+        let a = ((target_x * self.b_y as f64 - self.b_x as f64 * target_y) / determinant).round();
+        let b = ((self.a_x as f64 * target_y - target_x * self.a_y as f64) / determinant).round();
+        let (a, b) = (a as i64, b as i64);
+
+        let prize_x = a * self.a_x + b * self.b_x - correction;
+        let prize_y = a * self.a_y + b * self.b_y - correction;
+
+        (a >= 0 && b >= 0 && prize_x > 0 && prize_y > 0).then_some((prize_x, prize_y))
+    }
+}
+
+fn robots(rng: &mut ChaCha8Rng, count: usize) -> String {
+    (0..count)
+        .map(|_| {
+            format!(
+                "p={},{} v={},{}\n",
+                rng.random_range(0..day_14::AREA_WIDTH),
+                rng.random_range(0..day_14::AREA_HEIGHT),
+                rng.random_range(-99..=99),
+                rng.random_range(-99..=99)
+            )
+        })
+        .collect()
+}
+
+fn warehouse(rng: &mut ChaCha8Rng, side: usize) -> String {
+    const MOVES: [char; 4] = ['^', 'v', '<', '>'];
+    const MOVES_PER_LINE: usize = 1000;
+
+    // Walls all the way around keep the robot and boxes inside the warehouse.
+    let mut rows = (0..side)
+        .map(|y| {
+            (0..side)
+                .map(|x| {
+                    if x == 0 || y == 0 || x == side - 1 || y == side - 1 || rng.random_bool(0.08) {
+                        '#'
+                    } else if rng.random_bool(0.25) {
+                        'O'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let robot_x = rng.random_range(1..side - 1);
+    let robot_y = rng.random_range(1..side - 1);
+    rows[robot_y][robot_x] = '@';
+
+    let moves = (0..side * 400)
+        .map(|_| MOVES[rng.random_range(0..MOVES.len())])
+        .collect::<Vec<_>>();
+
+    let moves = moves
+        .chunks(MOVES_PER_LINE)
+        .map(|line| line.iter().collect::<String>() + "\n")
+        .collect::<String>();
+
+    render(&rows) + "\n" + &moves
+}
+
+fn maze(rng: &mut ChaCha8Rng, side: usize) -> String {
+    // Corridors run between the odd rows and columns, with walls all the way around.
+    let side = side | 1;
+    let mut rows = vec![vec!['#'; side]; side];
+
+    let mut stack = vec![(1usize, side - 2)];
+    rows[side - 2][1] = '.';
+
+    while let Some(&(x, y)) = stack.last() {
+        let mut directions = [(0, -2), (2, 0), (0, 2), (-2, 0)];
+        directions.shuffle(rng);
+
+        let next = directions.iter().find_map(|&(dx, dy)| {
+            let next_x = x.checked_add_signed(dx)?;
+            let next_y = y.checked_add_signed(dy)?;
+
+            (next_x < side - 1 && next_y < side - 1 && rows[next_y][next_x] == '#')
+                .then_some((next_x, next_y))
+        });
+
+        match next {
+            Some((next_x, next_y)) => {
+                rows[(y + next_y) / 2][(x + next_x) / 2] = '.';
+                rows[next_y][next_x] = '.';
+                stack.push((next_x, next_y));
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    // Knock out some walls between corridors, so there is more than one way through.
+    for _ in 0..side * side / 50 {
+        let x = rng.random_range(1..side - 1);
+        let y = rng.random_range(1..side - 1);
+
+        if (x + y) % 2 == 1 {
+            rows[y][x] = '.';
+        }
+    }
+
+    rows[side - 2][1] = 'S';
+    rows[1][side - 2] = 'E';
+
+    render(&rows)
+}
+
+fn program(rng: &mut ChaCha8Rng, instructions: usize) -> String {
+    const OPCODES: [u8; 7] = [0, 1, 2, 4, 5, 6, 7];
+
+    loop {
+        // The only jump is back to the start at the end, and `adv 3` divides A by 8 every time
+        // round, so the program always halts once A reaches 0.
+        let mut body = vec![[0, 3], [5, rng.random_range(0..=6)]];
+
+        for _ in 3..instructions {
+            let opcode = OPCODES[rng.random_range(0..OPCODES.len())];
+            let operand = if opcode == 1 || opcode == 4 {
+                rng.random_range(0..=7)
+            } else {
+                rng.random_range(0..=6)
+            };
+
+            body.push([opcode, operand]);
+        }
+
+        body.shuffle(rng);
+        body.push([3, 0]);
+
+        let program = body.iter().flatten().map(u8::to_string).collect::<Vec<_>>();
+
+        let input = format!(
+            "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+            rng.random_range(1..=i32::MAX),
+            program.join(",")
+        );
+
+        // The parser also rejects a reserved operand after any 3 that could be read as a jump,
+        // even one that is an operand here, so try again rather than second-guess it.
+        if day_17::Day17::parse(&input).is_ok() {
+            return input;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::PartSelection;
+
+    use super::*;
+    use crate::days::find_day;
+
+    #[test]
+    fn generate_works() {
+        for generator in &GENERATORS {
+            let day = find_day(generator.day).unwrap();
+
+            for seed in 0..3 {
+                for size in [generator.minimum_size, generator.minimum_size + 1, 12] {
+                    let input = generator.generate(seed, size);
+                    assert_eq!(input, generator.generate(seed, size));
+
+                    let selection = PartSelection::Both.excluding(day.interactive_parts);
+
+                    if let Err(error) = (day.solve)(&input, selection.unwrap()) {
+                        panic!(
+                            "day {} seed {} size {} did not parse: {}\n{}",
+                            generator.day, seed, size, error, input
+                        );
+                    }
+                }
+            }
+
+            assert_ne!(generator.generate(0, 12), generator.generate(1, 12));
+        }
+    }
+}
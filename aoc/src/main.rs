@@ -0,0 +1,820 @@
+mod answers;
+mod bench;
+mod days;
+mod export;
+mod fetch;
+mod generate;
+mod scaffold;
+mod selection;
+mod verify;
+mod visualize;
+mod watch;
+
+use std::{
+    fs, io,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use answers::Answers;
+use bench::{bench_day, Baseline, BenchOptions, BenchOutcome};
+use clap::{ArgAction, Parser, Subcommand};
+use common::{
+    budget::solve_within,
+    cli::parse_seconds,
+    input::InputSource,
+    logging,
+    pool::{self, THREADS_ENV_VAR},
+    report::{Format, Record},
+    solution::{AnyParseError, PartRun, Run},
+    Day, Part, PartSelection,
+};
+use days::{find_day, DAYS};
+use export::{Export, FrameRange};
+use fetch::{
+    read_session, FetchError, Fetched, Fetcher, RateLimit, UreqBackend, BASE_URL, REQUEST_INTERVAL,
+    SESSION_ENV_VAR, SESSION_FILE,
+};
+use generate::find_generator;
+use scaffold::scaffold_day;
+use selection::DaySelection;
+use verify::{print_table, verify_day, Status};
+use visualize::{with_frames, FramesVisitor, VISUALIZED_DAYS};
+use visualizer::{parse_speed, play, Frames, Options};
+use watch::{watch, WatchOptions};
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Threads for parallel searches. Defaults to one per CPU.
+    #[arg(long, global = true, env = THREADS_ENV_VAR)]
+    threads: Option<NonZeroUsize>,
+
+    /// Log progress to stderr. Repeat for more detail: `-vv` for debug output, `-vvv` for
+    /// traces of every step.
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solves the selected days against their puzzle inputs.
+    Run {
+        /// Days to run: `all`, `12`, `1..5`, `1..=17` or a comma-separated list of those.
+        days: DaySelection,
+
+        /// Which part of each puzzle to solve.
+        #[arg(long, value_enum, default_value_t = PartSelection::Both)]
+        part: PartSelection,
+
+        /// How to print the answers.
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Give up on any part still running after this many seconds and move on to the next
+        /// day.
+        #[arg(long, value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+    },
+    /// Checks the selected days' puzzle inputs against the assumptions their solutions make,
+    /// without solving them.
+    Check {
+        /// Days to check: `all`, `12`, `1..5`, `1..=17` or a comma-separated list of those.
+        days: DaySelection,
+    },
+    /// Times parsing and solving the selected days, comparing against a stored baseline.
+    Bench {
+        /// Days to benchmark: `all`, `12`, `1..5`, `1..=17` or a comma-separated list of those.
+        days: DaySelection,
+
+        /// Which part of each puzzle to benchmark.
+        #[arg(long, value_enum, default_value_t = PartSelection::Both)]
+        part: PartSelection,
+
+        /// How many times to time parsing and each part.
+        #[arg(long, default_value_t = 10)]
+        iterations: usize,
+
+        /// Baseline file holding the median timings to compare against.
+        #[arg(long, default_value_os_t = default_baseline_path())]
+        baseline: PathBuf,
+
+        /// Write the measured medians to the baseline file instead of comparing against it.
+        #[arg(long)]
+        save_baseline: bool,
+
+        /// How many percent slower than the baseline a median may be before it is flagged.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Re-runs the selected days against every input with a confirmed answer and reports any
+    /// answers that changed.
+    Verify {
+        /// Days to verify: `all`, `12`, `1..5`, `1..=17` or a comma-separated list of those.
+        #[arg(default_value = "all")]
+        days: DaySelection,
+
+        /// Answers file holding the confirmed answer for each day, part and input.
+        #[arg(long, default_value_os_t = default_answers_path())]
+        answers: PathBuf,
+
+        /// Record the current answer for every part that has no confirmed answer yet.
+        #[arg(long)]
+        record: bool,
+    },
+    /// Downloads the selected days' puzzle inputs from adventofcode.com to where the days read
+    /// them, skipping any day that already has one.
+    Fetch {
+        /// Days to fetch: `all`, `12`, `1..5`, `1..=17` or a comma-separated list of those.
+        days: DaySelection,
+    },
+    /// Runs a day's tests and solves it, then again whenever a file in its crate or its input
+    /// changes, showing how each answer changed and how long the run took.
+    Watch {
+        /// The day to watch.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Which part of the puzzle to solve.
+        #[arg(long, value_enum, default_value_t = PartSelection::Both)]
+        part: PartSelection,
+
+        /// Path to the puzzle input, instead of the day's default input.
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// How often to check for changes, in milliseconds.
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+    /// Steps through a day's simulation in the terminal: days 6, 12, 14, 15 and 16.
+    Visualize {
+        /// The day to visualize.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Which part's simulation to show, for days where they differ.
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Path to the puzzle input, instead of the day's default input.
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Frames per second while playing.
+        #[arg(long, default_value_t = 10.0, value_parser = parse_speed)]
+        speed: f64,
+    },
+    /// Writes a day's simulation to an image, a GIF or a directory of frames: days 6, 12, 14, 15
+    /// and 16.
+    Export {
+        /// The day to export.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Which part's simulation to export, for days where they differ.
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Path to the puzzle input, instead of the day's default input.
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Where to write: a `.ppm` or `.png` image of one frame, a `.gif` animation, or otherwise
+        /// a directory of numbered PNG frames.
+        #[arg(long)]
+        output: PathBuf,
+
+        /// The frame written to a single image. Defaults to the last frame.
+        #[arg(long)]
+        frame: Option<usize>,
+
+        /// The frames written to a GIF or directory: `12..40`, `12..=40` or `12..`.
+        #[arg(long, default_value = "0..")]
+        frames: FrameRange,
+
+        /// Width and height in pixels of each cell.
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..))]
+        scale: u32,
+
+        /// Hundredths of a second to show each frame of a GIF for.
+        #[arg(long, default_value_t = 10)]
+        delay: u16,
+    },
+    /// Writes a random input for a day, shaped like the real puzzle input, for timing the solution
+    /// on larger inputs.
+    Generate {
+        /// The day to generate an input for.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// How large to make the input, in the day's own unit: lines, grid side length, digits or
+        /// instructions. Defaults to about the size of the real input.
+        #[arg(long)]
+        size: Option<usize>,
+
+        /// Seed for the random input, which is printed to stderr when one is picked.
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Where to write the input, instead of stdout.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Creates the `day-N` crate with the usual layout and registers it with the workspace and
+    /// this runner.
+    New {
+        /// The day to create.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    logging::init(cli.verbose);
+
+    if let Some(threads) = cli.threads {
+        pool::set_threads(threads);
+    }
+
+    match cli.command {
+        Command::Run {
+            days,
+            part,
+            format,
+            timeout,
+        } => {
+            let days = resolve_days(&days);
+
+            let mut failed = false;
+
+            for day in days {
+                let succeeded = match format {
+                    Format::Text => run_day(day, part, timeout),
+                    Format::Json => run_day_json(day, part, timeout),
+                };
+
+                if !succeeded {
+                    failed = true;
+                }
+            }
+
+            if failed {
+                process::exit(1);
+            }
+        }
+        Command::Check { days } => {
+            let days = resolve_days(&days);
+
+            let mut failed = false;
+
+            for day in days {
+                if !check_day(day) {
+                    failed = true;
+                }
+            }
+
+            if failed {
+                process::exit(1);
+            }
+        }
+        Command::Bench {
+            days,
+            part,
+            iterations,
+            baseline,
+            save_baseline,
+            threshold,
+        } => {
+            let days = resolve_days(&days);
+
+            let stored_baseline = Baseline::load(&baseline).unwrap_or_else(|error| {
+                eprintln!(
+                    "error: could not read baseline from {}: {}",
+                    baseline.display(),
+                    error
+                );
+                process::exit(1);
+            });
+
+            let no_baseline = Baseline::default();
+            let options = BenchOptions {
+                part,
+                iterations,
+                threshold,
+                compare_to: if save_baseline {
+                    &no_baseline
+                } else {
+                    &stored_baseline
+                },
+            };
+
+            let mut results = stored_baseline.clone();
+            let mut failed = false;
+            let mut regressions = 0;
+
+            for day in days {
+                match bench_day(day, &options, &mut results) {
+                    BenchOutcome::Measured {
+                        regressions: day_regressions,
+                    } => regressions += day_regressions,
+                    BenchOutcome::Failed => failed = true,
+                }
+            }
+
+            if save_baseline {
+                if let Err(error) = results.save(&baseline) {
+                    eprintln!(
+                        "error: could not write baseline to {}: {}",
+                        baseline.display(),
+                        error
+                    );
+                    process::exit(1);
+                }
+
+                println!("Saved baseline to {}", baseline.display());
+            }
+
+            if regressions > 0 {
+                eprintln!(
+                    "error: {} benchmark(s) regressed by more than {}%",
+                    regressions, threshold
+                );
+                failed = true;
+            }
+
+            if failed {
+                process::exit(1);
+            }
+        }
+        Command::Verify {
+            days,
+            answers: answers_path,
+            record,
+        } => {
+            let days = resolve_days(&days);
+
+            let mut answers = Answers::load(&answers_path).unwrap_or_else(|error| {
+                eprintln!(
+                    "error: could not read answers from {}: {}",
+                    answers_path.display(),
+                    error
+                );
+                process::exit(1);
+            });
+
+            let rows = days
+                .into_iter()
+                .flat_map(|day| verify_day(day, &mut answers, record))
+                .collect::<Vec<_>>();
+
+            print_table(&rows);
+
+            let count = |status| rows.iter().filter(|row| row.status == status).count();
+
+            println!();
+            println!(
+                "{} passed, {} failed, {} errored, {} missing, {} recorded",
+                count(Status::Pass),
+                count(Status::Fail),
+                count(Status::Error),
+                count(Status::Missing),
+                count(Status::Recorded)
+            );
+
+            if record && count(Status::Recorded) > 0 {
+                if let Err(error) = answers.save(&answers_path) {
+                    eprintln!(
+                        "error: could not write answers to {}: {}",
+                        answers_path.display(),
+                        error
+                    );
+                    process::exit(1);
+                }
+
+                println!("Saved answers to {}", answers_path.display());
+            }
+
+            if count(Status::Fail) > 0 || count(Status::Error) > 0 {
+                process::exit(1);
+            }
+        }
+        Command::Fetch { days } => {
+            let days = resolve_days(&days);
+
+            let session =
+                read_session(&workspace_root().join(SESSION_FILE)).unwrap_or_else(|error| {
+                    eprintln!(
+                    "error: no session token: set {} or save it to {} in the workspace root: {}",
+                    SESSION_ENV_VAR, SESSION_FILE, error
+                );
+                    process::exit(1);
+                });
+
+            let fetcher = Fetcher {
+                backend: UreqBackend::default(),
+                base_url: BASE_URL.to_string(),
+                session,
+                rate_limit: RateLimit {
+                    path: workspace_root().join("target/aoc-fetch-last-request"),
+                    interval: REQUEST_INTERVAL,
+                },
+            };
+
+            let mut failed = false;
+
+            for day in days {
+                match fetcher.fetch(day.number, &input_path(day.number)) {
+                    Ok(Fetched::Saved(bytes)) => println!(
+                        "Day {}: saved {} bytes to {}",
+                        day.number,
+                        bytes,
+                        default_input(day.number)
+                    ),
+                    Ok(Fetched::AlreadyPresent) => println!(
+                        "Day {}: {} already exists, not fetched",
+                        day.number,
+                        default_input(day.number)
+                    ),
+                    Err(error) => {
+                        println!("Day {}: could not fetch input: {}", day.number, error);
+                        failed = true;
+
+                        // Every other request would be rejected too.
+                        if matches!(error, FetchError::SessionRejected) {
+                            break;
+                        }
+                    }
+                }
+            }
+
+            if failed {
+                process::exit(1);
+            }
+        }
+        Command::Watch {
+            day,
+            part,
+            input,
+            interval,
+        } => {
+            if find_day(day).is_none() {
+                eprintln!("error: day {} has not been solved", day);
+                process::exit(1);
+            }
+
+            // The day is run from the workspace root, so a relative input path has to be made
+            // absolute first.
+            let input = match input {
+                Some(input) => std::path::absolute(&input).unwrap_or_else(|error| {
+                    eprintln!("error: could not resolve {}: {}", input.display(), error);
+                    process::exit(1);
+                }),
+                None => input_path(day),
+            };
+
+            watch(&WatchOptions {
+                day,
+                part,
+                input,
+                interval: Duration::from_millis(interval),
+            });
+        }
+        Command::Visualize {
+            day,
+            part,
+            input,
+            speed,
+        } => {
+            let part = if part == 1 { Part::One } else { Part::Two };
+            let options = Options {
+                title: format!("Day {} part {}", day, part),
+                speed,
+            };
+
+            let played = with_simulation(day, part, input, Play(&options));
+
+            if let Err(error) = played {
+                eprintln!("error: could not visualize day {}: {}", day, error);
+                process::exit(1);
+            }
+        }
+        Command::Export {
+            day,
+            part,
+            input,
+            output,
+            frame,
+            frames,
+            scale,
+            delay,
+        } => {
+            let part = if part == 1 { Part::One } else { Part::Two };
+            let export = Export {
+                output: output.clone(),
+                frame,
+                frames,
+                scale: scale as usize,
+                delay,
+            };
+
+            if let Err(error) = with_simulation(day, part, input, export) {
+                eprintln!(
+                    "error: could not export day {} to {}: {}",
+                    day,
+                    output.display(),
+                    error
+                );
+                process::exit(1);
+            }
+
+            println!("Wrote {}", output.display());
+        }
+        Command::Generate {
+            day,
+            size,
+            seed,
+            output,
+        } => {
+            let generator = find_generator(day).unwrap_or_else(|| {
+                eprintln!("error: day {} has no input generator", day);
+                process::exit(1);
+            });
+
+            let size = size.unwrap_or(generator.default_size);
+            if size < generator.minimum_size {
+                eprintln!(
+                    "error: day {} inputs need at least {} {}",
+                    day, generator.minimum_size, generator.unit
+                );
+                process::exit(1);
+            }
+
+            let seed = seed.unwrap_or_else(|| {
+                let seed = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |duration| duration.as_nanos() as u64);
+
+                eprintln!("Seed: {}", seed);
+                seed
+            });
+
+            let input = generator.generate(seed, size);
+
+            match output {
+                Some(output) => {
+                    if let Err(error) = fs::write(&output, input) {
+                        eprintln!(
+                            "error: could not write input to {}: {}",
+                            output.display(),
+                            error
+                        );
+                        process::exit(1);
+                    }
+                }
+                None => print!("{}", input),
+            }
+        }
+        Command::New { day } => {
+            if let Err(error) = scaffold_day(&workspace_root(), day) {
+                eprintln!("error: could not create day {}: {}", day, error);
+                process::exit(1);
+            }
+
+            println!(
+                "Created day-{}. Fill in its example input and answers:",
+                day
+            );
+            println!("  day-{}/src/aoc-input/example-input.txt", day);
+            println!("  day-{}/src/aoc-input/example-answer-1.txt", day);
+            println!("  day-{}/src/aoc-input/example-answer-2.txt", day);
+            println!(
+                "Then fetch its input with `cargo run -p aoc -- fetch {}`.",
+                day
+            );
+        }
+    }
+}
+
+/// Plays a simulation in the terminal.
+struct Play<'a>(&'a Options);
+
+impl FramesVisitor for Play<'_> {
+    type Output = io::Result<()>;
+
+    fn visit<F: Frames>(self, frames: F) -> Self::Output {
+        play(frames, self.0)
+    }
+}
+
+/// Reads the input and hands the day's simulation to `visitor`, exiting if the day has no
+/// simulation or its input cannot be read or parsed.
+fn with_simulation<V: FramesVisitor>(
+    day: u8,
+    part: Part,
+    input: Option<PathBuf>,
+    visitor: V,
+) -> V::Output {
+    if !VISUALIZED_DAYS.contains(&day) {
+        eprintln!("error: day {} has no visualization", day);
+        process::exit(1);
+    }
+
+    let source = InputSource::File(input.unwrap_or_else(|| input_path(day)));
+    let input = source.read().unwrap_or_else(|error| {
+        eprintln!(
+            "error: could not read input from {}: {}",
+            source.describe(),
+            error
+        );
+        process::exit(1);
+    });
+
+    with_frames(day, &input, part, visitor).unwrap_or_else(|error| {
+        eprintln!("error: could not parse day {} input: {}", day, error);
+        process::exit(1);
+    })
+}
+
+fn resolve_days(selection: &DaySelection) -> Vec<&'static Day> {
+    match selection {
+        DaySelection::All => DAYS.iter().collect(),
+        DaySelection::Days(numbers) => numbers
+            .iter()
+            .map(|&number| {
+                find_day(number).unwrap_or_else(|| {
+                    eprintln!("error: day {} has not been solved", number);
+                    process::exit(1);
+                })
+            })
+            .collect(),
+    }
+}
+
+/// Prints the answer to each selected part, skipping interactive parts.
+fn run_day(day: &Day, part: PartSelection, timeout: Option<Duration>) -> bool {
+    let part_runs = match part.excluding(day.interactive_parts) {
+        Some(selection) => {
+            let Some(input) = read_input(day) else {
+                return false;
+            };
+
+            let run = match solve_day(day, input, selection, timeout) {
+                Ok(run) => run,
+                Err(error) => {
+                    eprintln!("Day {}: could not parse input: {}", day.number, error);
+                    return false;
+                }
+            };
+
+            println!("Day {} (parsed in {:?})", day.number, run.parse_duration);
+            run.parts
+        }
+        None => {
+            println!("Day {}", day.number);
+            Vec::new()
+        }
+    };
+
+    for part in part.parts() {
+        match part_runs.iter().find(|part_run| part_run.part == part) {
+            Some(part_run) => println!(
+                "  Part {}: {} ({:?})",
+                part_run.part, part_run.answer, part_run.duration
+            ),
+            None => println!("  Part {}: skipped (interactive)", part),
+        }
+    }
+
+    !part_runs.iter().any(PartRun::did_not_finish)
+}
+
+/// Solves the selected parts, giving up on any still running after `timeout`. A day that times
+/// out carries on in the background until the command exits.
+fn solve_day(
+    day: &Day,
+    input: String,
+    selection: PartSelection,
+    timeout: Option<Duration>,
+) -> Result<Run, AnyParseError> {
+    match timeout {
+        Some(timeout) => {
+            let solve = day.solve_reporting;
+            solve_within(timeout, selection, move |report| {
+                solve(&input, selection, report)
+            })
+        }
+        None => (day.solve)(&input, selection),
+    }
+}
+
+/// Prints every violation in the day's input, returning whether there were none.
+fn check_day(day: &Day) -> bool {
+    let Some(input) = read_input(day) else {
+        return false;
+    };
+
+    let violations = (day.check)(&input);
+
+    if violations.is_empty() {
+        println!("Day {}: ok", day.number);
+        return true;
+    }
+
+    println!("Day {}: {} problem(s)", day.number, violations.len());
+
+    for violation in violations {
+        println!("  {}", violation);
+    }
+
+    false
+}
+
+/// Prints one JSON [`Record`] per selected part, skipping interactive parts.
+fn run_day_json(day: &Day, part: PartSelection, timeout: Option<Duration>) -> bool {
+    let input_name = default_input(day.number);
+
+    let run = read_input_file(&input_path(day.number))
+        .map_err(|error| format!("could not read input: {}", error))
+        .and_then(|input| match part.excluding(day.interactive_parts) {
+            Some(selection) => solve_day(day, input, selection, timeout)
+                .map(|run| run.parts)
+                .map_err(|error| format!("could not parse input: {}", error)),
+            None => Ok(Vec::new()),
+        });
+
+    let part_runs = match run {
+        Ok(part_runs) => part_runs,
+        Err(error) => {
+            println!(
+                "{}",
+                Record::error(day.number, &input_name, error).to_json()
+            );
+            return false;
+        }
+    };
+
+    let mut finished = true;
+
+    for part in part.parts() {
+        let record = match part_runs.iter().find(|part_run| part_run.part == part) {
+            Some(part_run) => Record::solved(day.number, &input_name, part_run),
+            None => Record::skipped(day.number, &input_name, part),
+        };
+
+        finished &= record.error.is_none();
+        println!("{}", record.to_json());
+    }
+
+    finished
+}
+
+/// Reads the day's puzzle input, reporting to stderr if it cannot be read.
+fn read_input(day: &Day) -> Option<String> {
+    let source = InputSource::File(input_path(day.number));
+
+    match source.read() {
+        Ok(input) => Some(input),
+        Err(error) => {
+            eprintln!(
+                "Day {}: could not read input from {}: {}",
+                day.number,
+                source.describe(),
+                error
+            );
+            None
+        }
+    }
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn read_input_file(path: &Path) -> io::Result<String> {
+    InputSource::File(path.to_path_buf()).read()
+}
+
+/// The day's default input path, relative to the workspace root.
+fn default_input(day: u8) -> String {
+    format!("day-{}/src/aoc-input/input.txt", day)
+}
+
+fn input_path(day: u8) -> PathBuf {
+    workspace_root().join(default_input(day))
+}
+
+fn default_baseline_path() -> PathBuf {
+    workspace_root().join("bench-baseline.tsv")
+}
+
+fn default_answers_path() -> PathBuf {
+    workspace_root().join("answers.tsv")
+}
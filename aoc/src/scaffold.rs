@@ -0,0 +1,253 @@
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+};
+
+const CARGO_TOML_TEMPLATE: &str = include_str!("../templates/Cargo.toml.template");
+const MAIN_RS_TEMPLATE: &str = include_str!("../templates/main.rs.template");
+const LIB_RS_TEMPLATE: &str = include_str!("../templates/lib.rs.template");
+const FUZZ_TARGET_TEMPLATE: &str = include_str!("../templates/fuzz_target.rs.template");
+
+#[derive(Debug)]
+pub(crate) enum ScaffoldError {
+    AlreadyExists(u8),
+    Io(io::Error),
+    Unrecognised(&'static str),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::AlreadyExists(day) => write!(f, "day-{} already exists", day),
+            ScaffoldError::Io(error) => write!(f, "{}", error),
+            ScaffoldError::Unrecognised(file) => {
+                write!(f, "could not find where to register the day in {}", file)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(error: io::Error) -> Self {
+        ScaffoldError::Io(error)
+    }
+}
+
+/// Creates the `day-N` crate from the templates and registers it with the workspace, the `aoc`
+/// runner and the fuzz targets.
+pub(crate) fn scaffold_day(root: &Path, day: u8) -> Result<(), ScaffoldError> {
+    let crate_dir = root.join(format!("day-{}", day));
+    if crate_dir.exists() {
+        return Err(ScaffoldError::AlreadyExists(day));
+    }
+
+    let workspace_manifest_path = root.join("Cargo.toml");
+    let aoc_manifest_path = root.join("aoc/Cargo.toml");
+    let days_path = root.join("aoc/src/days.rs");
+    let fuzz_manifest_path = root.join("fuzz/Cargo.toml");
+
+    // Work out every edit before writing anything, so a failure leaves the tree untouched.
+    let workspace_manifest =
+        add_workspace_member(&fs::read_to_string(&workspace_manifest_path)?, day)
+            .ok_or(ScaffoldError::Unrecognised("Cargo.toml"))?;
+    let aoc_manifest = add_day_dependency(&fs::read_to_string(&aoc_manifest_path)?, day)
+        .ok_or(ScaffoldError::Unrecognised("aoc/Cargo.toml"))?;
+    let fuzz_manifest = add_day_dependency(&fs::read_to_string(&fuzz_manifest_path)?, day)
+        .map(|manifest| add_fuzz_target(&manifest, day))
+        .ok_or(ScaffoldError::Unrecognised("fuzz/Cargo.toml"))?;
+    let days = add_day(&fs::read_to_string(&days_path)?, day)
+        .ok_or(ScaffoldError::Unrecognised("aoc/src/days.rs"))?;
+
+    let input_dir = crate_dir.join("src/aoc-input");
+    fs::create_dir_all(&input_dir)?;
+
+    fs::write(
+        crate_dir.join("Cargo.toml"),
+        fill_in(CARGO_TOML_TEMPLATE, day),
+    )?;
+    fs::write(
+        crate_dir.join("src/main.rs"),
+        fill_in(MAIN_RS_TEMPLATE, day),
+    )?;
+    fs::write(crate_dir.join("src/lib.rs"), fill_in(LIB_RS_TEMPLATE, day))?;
+    fs::write(input_dir.join("example-input.txt"), "")?;
+    fs::write(input_dir.join("example-answer-1.txt"), "")?;
+    fs::write(input_dir.join("example-answer-2.txt"), "")?;
+    fs::write(
+        root.join(format!("fuzz/fuzz_targets/day_{}.rs", day)),
+        fill_in(FUZZ_TARGET_TEMPLATE, day),
+    )?;
+
+    fs::write(workspace_manifest_path, workspace_manifest)?;
+    fs::write(aoc_manifest_path, aoc_manifest)?;
+    fs::write(days_path, days)?;
+    fs::write(fuzz_manifest_path, fuzz_manifest)?;
+
+    Ok(())
+}
+
+fn fill_in(template: &str, day: u8) -> String {
+    template.replace("{day}", &day.to_string())
+}
+
+/// Adds `day-N` to the workspace `members`, keeping them sorted.
+fn add_workspace_member(manifest: &str, day: u8) -> Option<String> {
+    let start = manifest.find("members = [")? + "members = [".len();
+    let end = start + manifest[start..].find(']')?;
+
+    let mut members = manifest[start..end]
+        .split(',')
+        .map(|member| member.trim().trim_matches('"'))
+        .filter(|member| !member.is_empty())
+        .map(String::from)
+        .collect::<Vec<_>>();
+
+    members.push(format!("day-{}", day));
+    members.sort();
+    members.dedup();
+
+    let members = members
+        .iter()
+        .map(|member| format!("\"{}\"", member))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Some(format!(
+        "{}{}{}",
+        &manifest[..start],
+        members,
+        &manifest[end..]
+    ))
+}
+
+/// Adds a `day-N` path dependency after the last day that comes before it.
+fn add_day_dependency(manifest: &str, day: u8) -> Option<String> {
+    let lines = manifest.lines().collect::<Vec<_>>();
+
+    let index = insertion_index(&lines, day, |line| day_number(line, "day-", " "))?;
+
+    let mut lines = lines.into_iter().map(String::from).collect::<Vec<_>>();
+    lines.insert(
+        index,
+        format!("day-{} = {{ path = \"../day-{}\" }}", day, day),
+    );
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds a `day_N` fuzz target at the end of the manifest.
+fn add_fuzz_target(manifest: &str, day: u8) -> String {
+    format!(
+        "{}\n[[bin]]\nname = \"day_{}\"\npath = \"fuzz_targets/day_{}.rs\"\ntest = false\ndoc = false\nbench = false\n",
+        manifest, day, day
+    )
+}
+
+/// Adds the day to the `DAYS` array, keeping it in order.
+fn add_day(days: &str, day: u8) -> Option<String> {
+    let lines = days.lines().collect::<Vec<_>>();
+
+    let entries = lines
+        .iter()
+        .filter(|line| day_number(line.trim(), "Day::of::<day_", ":").is_some())
+        .count();
+
+    let index = insertion_index(&lines, day, |line| {
+        day_number(line.trim(), "Day::of::<day_", ":")
+    })?;
+
+    let mut lines = lines
+        .into_iter()
+        .map(|line| {
+            line.replace(
+                &format!("[Day; {}]", entries),
+                &format!("[Day; {}]", entries + 1),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    lines.insert(index, format!("    Day::of::<day_{}::Day{}>(),", day, day));
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Where to insert `day` among the lines that `day_number` recognises, so that they stay in order.
+fn insertion_index(
+    lines: &[&str],
+    day: u8,
+    day_number: impl Fn(&str) -> Option<u8>,
+) -> Option<usize> {
+    let numbered = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, day_number(line)?)))
+        .collect::<Vec<_>>();
+
+    match numbered.iter().rev().find(|(_, number)| *number < day) {
+        Some((index, _)) => Some(index + 1),
+        None => numbered.first().map(|(index, _)| *index),
+    }
+}
+
+/// The day number in a line starting with `prefix` and followed by `terminator`.
+fn day_number(line: &str, prefix: &str, terminator: &str) -> Option<u8> {
+    let rest = line.strip_prefix(prefix)?;
+    let end = rest.find(terminator)?;
+
+    rest[..end].parse().ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn add_workspace_member_works() {
+        assert_eq!(
+            add_workspace_member(
+                "[workspace]\nmembers = [\"aoc\", \"day-1\", \"day-17\", \"day-2\", \"grid\"]\n",
+                18
+            ),
+            Some(String::from(
+                "[workspace]\nmembers = [\"aoc\", \"day-1\", \"day-17\", \"day-18\", \"day-2\", \"grid\"]\n"
+            ))
+        );
+    }
+
+    #[test]
+    fn add_day_dependency_works() {
+        assert_eq!(
+            add_day_dependency(
+                "[dependencies]\ncommon.workspace = true\nday-1 = { path = \"../day-1\" }\nday-17 = { path = \"../day-17\" }\n",
+                18
+            ),
+            Some(String::from(
+                "[dependencies]\ncommon.workspace = true\nday-1 = { path = \"../day-1\" }\nday-17 = { path = \"../day-17\" }\nday-18 = { path = \"../day-18\" }\n"
+            ))
+        );
+    }
+
+    #[test]
+    fn add_fuzz_target_works() {
+        assert_eq!(
+            add_fuzz_target("[workspace]\nmembers = [\".\"]\n", 18),
+            "[workspace]\nmembers = [\".\"]\n\n[[bin]]\nname = \"day_18\"\npath = \"fuzz_targets/day_18.rs\"\ntest = false\ndoc = false\nbench = false\n"
+        );
+    }
+
+    #[test]
+    fn add_day_works() {
+        assert_eq!(
+            add_day(
+                "pub(crate) const DAYS: [Day; 2] = [\n    Day::of::<day_1::Day1>(),\n    Day::of::<day_17::Day17>(),\n];\n",
+                3
+            ),
+            Some(String::from(
+                "pub(crate) const DAYS: [Day; 3] = [\n    Day::of::<day_1::Day1>(),\n    Day::of::<day_3::Day3>(),\n    Day::of::<day_17::Day17>(),\n];\n"
+            ))
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use std::{fmt, str::FromStr};
+
+const FIRST_DAY: u8 = 1;
+const LAST_DAY: u8 = 25;
+
+/// The days to act on, written as `all`, a single day (`12`), a range (`1..5`, `1..=17`) or a
+/// comma-separated list of those (`1,3,5..=7`).
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum DaySelection {
+    All,
+    Days(Vec<u8>),
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct DaySelectionError(String);
+
+impl fmt::Display for DaySelectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for DaySelectionError {}
+
+impl FromStr for DaySelection {
+    type Err = DaySelectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Self::All);
+        }
+
+        let mut days = Vec::new();
+
+        for part in s.split(',') {
+            let (start, end) = if let Some((start, end)) = part.split_once("..=") {
+                (parse_day(start)?, parse_day(end)?)
+            } else if let Some((start, end)) = part.split_once("..") {
+                let end = parse_day(end)?;
+                let end = end.checked_sub(1).ok_or_else(|| {
+                    DaySelectionError(format!("`{}` does not include any days", part))
+                })?;
+                (parse_day(start)?, end)
+            } else {
+                let day = parse_day(part)?;
+                (day, day)
+            };
+
+            if start > end {
+                return Err(DaySelectionError(format!(
+                    "`{}` does not include any days",
+                    part
+                )));
+            }
+
+            for day in start..=end {
+                if !days.contains(&day) {
+                    days.push(day);
+                }
+            }
+        }
+
+        Ok(Self::Days(days))
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, DaySelectionError> {
+    let day = s
+        .trim()
+        .parse::<u8>()
+        .map_err(|_| DaySelectionError(format!("`{}` is not a day", s)))?;
+
+    if !(FIRST_DAY..=LAST_DAY).contains(&day) {
+        return Err(DaySelectionError(format!(
+            "day {} is outside of {}..={}",
+            day, FIRST_DAY, LAST_DAY
+        )));
+    }
+
+    Ok(day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn day_selection_from_str_works() {
+        assert_eq!(DaySelection::from_str("all"), Ok(DaySelection::All));
+        assert_eq!(
+            DaySelection::from_str("12"),
+            Ok(DaySelection::Days(vec![12]))
+        );
+        assert_eq!(
+            DaySelection::from_str("1..4"),
+            Ok(DaySelection::Days(vec![1, 2, 3]))
+        );
+        assert_eq!(
+            DaySelection::from_str("1..=4"),
+            Ok(DaySelection::Days(vec![1, 2, 3, 4]))
+        );
+        assert_eq!(
+            DaySelection::from_str("5,1..=2,2"),
+            Ok(DaySelection::Days(vec![5, 1, 2]))
+        );
+    }
+
+    #[test]
+    fn day_selection_from_str_rejects_invalid_days() {
+        assert!(DaySelection::from_str("0").is_err());
+        assert!(DaySelection::from_str("26").is_err());
+        assert!(DaySelection::from_str("day-1").is_err());
+        assert!(DaySelection::from_str("5..=3").is_err());
+        assert!(DaySelection::from_str("1..1").is_err());
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt::{self, Display};
+
+use common::{Answer, Day, Part, PartSelection};
+
+use crate::{answers::Answers, default_input, read_input_file, workspace_root};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Status {
+    Pass,
+    Fail,
+    Missing,
+    Recorded,
+    Skipped,
+    Error,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail => write!(f, "FAIL"),
+            Status::Missing => write!(f, "missing"),
+            Status::Recorded => write!(f, "recorded"),
+            Status::Skipped => write!(f, "skipped"),
+            Status::Error => write!(f, "error"),
+        }
+    }
+}
+
+pub(crate) struct Row {
+    day: u8,
+    part: Option<Part>,
+    input: String,
+    expected: String,
+    actual: String,
+    pub(crate) status: Status,
+}
+
+impl Row {
+    fn error(day: u8, input: &str, message: String) -> Self {
+        Self {
+            day,
+            part: None,
+            input: input.to_string(),
+            expected: String::new(),
+            actual: message,
+            status: Status::Error,
+        }
+    }
+}
+
+/// Re-runs `day` against its default input and every input with a recorded answer, comparing the
+/// answers. With `record`, answers that have not been recorded yet are added to `answers`.
+pub(crate) fn verify_day(day: &Day, answers: &mut Answers, record: bool) -> Vec<Row> {
+    let default_input = default_input(day.number);
+
+    let mut inputs = answers
+        .inputs(day.number)
+        .into_iter()
+        .map(String::from)
+        .collect::<Vec<_>>();
+
+    if !inputs.contains(&default_input) {
+        inputs.insert(0, default_input.clone());
+    }
+
+    let mut rows = Vec::new();
+
+    for input_name in inputs {
+        let Ok(input) = read_input_file(&workspace_root().join(&input_name)) else {
+            if input_name != default_input {
+                rows.push(Row::error(
+                    day.number,
+                    &input_name,
+                    String::from("input not found"),
+                ));
+            }
+            continue;
+        };
+
+        // Interactive parts wait for the user, so they are never verified.
+        let Some(selection) = PartSelection::Both.excluding(day.interactive_parts) else {
+            continue;
+        };
+
+        let run = match (day.solve)(&input, selection) {
+            Ok(run) => run,
+            Err(error) => {
+                rows.push(Row::error(day.number, &input_name, error.to_string()));
+                continue;
+            }
+        };
+
+        for part_run in run.parts {
+            let expected = answers
+                .get(day.number, part_run.part, &input_name)
+                .map(String::from);
+            let actual = part_run.answer.to_string();
+
+            let status = match part_run.answer {
+                Answer::Unsolved => Status::Skipped,
+                // Never recorded, as the reason it gave up is not an answer.
+                Answer::DidNotFinish(_) => Status::Error,
+                _ => match &expected {
+                    Some(expected) if *expected == actual => Status::Pass,
+                    Some(_) => Status::Fail,
+                    None if record => {
+                        answers.insert(day.number, part_run.part, &input_name, actual.clone());
+                        Status::Recorded
+                    }
+                    None => Status::Missing,
+                },
+            };
+
+            rows.push(Row {
+                day: day.number,
+                part: Some(part_run.part),
+                input: input_name.clone(),
+                expected: expected.unwrap_or_default(),
+                actual,
+                status,
+            });
+        }
+    }
+
+    rows
+}
+
+pub(crate) fn print_table(rows: &[Row]) {
+    let cells = rows
+        .iter()
+        .map(|row| {
+            [
+                row.day.to_string(),
+                row.part.map(|part| part.to_string()).unwrap_or_default(),
+                row.input.clone(),
+                row.expected.clone(),
+                row.actual.clone(),
+                row.status.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let header = ["Day", "Part", "Input", "Expected", "Actual", "Status"].map(String::from);
+
+    let widths = (0..header.len())
+        .map(|column| {
+            std::iter::once(&header)
+                .chain(&cells)
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    for row in std::iter::once(&header).chain(&cells) {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ");
+
+        println!("{}", line.trim_end());
+    }
+}
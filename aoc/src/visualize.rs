@@ -0,0 +1,49 @@
+use common::{solution::AnyParseError, Part, Solution};
+use visualizer::Frames;
+
+/// Days with a simulation that can be stepped through or exported.
+pub(crate) const VISUALIZED_DAYS: [u8; 5] = [6, 12, 14, 15, 16];
+
+/// Something to do with a day's simulation, whichever kind of cells it has.
+pub(crate) trait FramesVisitor {
+    type Output;
+
+    fn visit<F: Frames>(self, frames: F) -> Self::Output;
+}
+
+fn parse<S: Solution>(input: &str) -> Result<S::Input, AnyParseError> {
+    S::parse(input).map_err(|error| Box::new(error) as AnyParseError)
+}
+
+/// Builds the day's simulation for `input` and hands it to `visitor`. Only day 15 has a different
+/// simulation for each part, the scaled-up warehouse being part two's.
+pub(crate) fn with_frames<V: FramesVisitor>(
+    day: u8,
+    input: &str,
+    part: Part,
+    visitor: V,
+) -> Result<V::Output, AnyParseError> {
+    let output = match day {
+        6 => visitor.visit(day_6::GuardWalk::new(&parse::<day_6::Day6>(input)?)),
+        12 => visitor.visit(day_12::RegionMap::new(&parse::<day_12::Day12>(input)?)),
+        14 => visitor.visit(day_14::RobotMovements::new(
+            &parse::<day_14::Day14>(input)?,
+            day_14::AREA_WIDTH,
+            day_14::AREA_HEIGHT,
+        )),
+        15 => {
+            let (warehouse, movements) = parse::<day_15::Day15>(input)?;
+
+            let warehouse = match part {
+                Part::One => warehouse,
+                Part::Two => warehouse.scaled_up(),
+            };
+
+            visitor.visit(day_15::WarehouseMovements::new(&warehouse, &movements))
+        }
+        16 => visitor.visit(day_16::BestPaths::new(&parse::<day_16::Day16>(input)?)),
+        _ => unreachable!("day {} is not in VISUALIZED_DAYS", day),
+    };
+
+    Ok(output)
+}
@@ -0,0 +1,329 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use common::PartSelection;
+use serde_json::Value;
+
+use crate::workspace_root;
+
+/// The last time each file under the watched paths was modified.
+pub(crate) type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+pub(crate) struct WatchOptions {
+    pub(crate) day: u8,
+    pub(crate) part: PartSelection,
+    pub(crate) input: PathBuf,
+    pub(crate) interval: Duration,
+}
+
+/// One part's outcome from a run of the day's binary.
+#[derive(Debug, PartialEq)]
+pub(crate) struct PartOutcome {
+    /// `None` when the input could not be read or parsed, so no part was solved.
+    pub(crate) part: Option<u8>,
+    /// The answer, or why there is none.
+    pub(crate) result: Result<String, String>,
+    pub(crate) duration: Option<Duration>,
+}
+
+/// Runs the day's tests and solves the selected parts, then again every time a file in the day's
+/// crate or its input changes, until interrupted.
+pub(crate) fn watch(options: &WatchOptions) -> ! {
+    let paths = [
+        workspace_root().join(format!("day-{}", options.day)),
+        options.input.clone(),
+    ];
+
+    let mut answers = HashMap::new();
+    let mut snapshot = take_snapshot(&paths);
+
+    run(options, &mut answers);
+
+    loop {
+        thread::sleep(options.interval);
+
+        let current = take_snapshot(&paths);
+        let changed = changed_paths(&snapshot, &current);
+        snapshot = current;
+
+        if changed.is_empty() {
+            continue;
+        }
+
+        println!();
+        for path in &changed {
+            println!("Changed: {}", display_path(path));
+        }
+
+        run(options, &mut answers);
+    }
+}
+
+/// Runs the tests and solves the selected parts once, comparing each answer with the one from the
+/// previous run and replacing it.
+fn run(options: &WatchOptions, answers: &mut HashMap<u8, String>) {
+    let start = Instant::now();
+    let package = format!("day-{}", options.day);
+
+    println!("Testing day {}...", options.day);
+    match cargo(&["test", "-p", &package, "--quiet"]).status() {
+        Ok(status) if status.success() => println!("Tests passed"),
+        Ok(_) => println!("Tests FAILED"),
+        Err(error) => println!("Tests could not be run: {}", error),
+    }
+
+    println!("Solving day {}...", options.day);
+    let output = cargo(&["run", "-p", &package, "--quiet", "--", "--format", "json"])
+        .args(["--part", part_argument(options.part)])
+        .arg("--input")
+        .arg(&options.input)
+        .stderr(Stdio::inherit())
+        .output();
+
+    match output {
+        Ok(output) => {
+            let outcomes = parse_outcomes(&String::from_utf8_lossy(&output.stdout));
+
+            if outcomes.is_empty() {
+                println!("No answers, see the errors above");
+            }
+
+            for outcome in outcomes {
+                print_outcome(&outcome, answers);
+            }
+        }
+        Err(error) => println!("Day {} could not be run: {}", options.day, error),
+    }
+
+    println!("Finished in {:.2?}", start.elapsed());
+}
+
+fn print_outcome(outcome: &PartOutcome, answers: &mut HashMap<u8, String>) {
+    let duration = outcome
+        .duration
+        .map(|duration| format!(" ({:.2?})", duration))
+        .unwrap_or_default();
+
+    let Some(part) = outcome.part else {
+        if let Err(reason) = &outcome.result {
+            println!("  {}", reason);
+        }
+        return;
+    };
+
+    match &outcome.result {
+        Ok(answer) => {
+            let change = describe_change(answers.get(&part).map(String::as_str), answer);
+            println!("  Part {}: {}{}{}", part, answer, duration, change);
+            answers.insert(part, answer.clone());
+        }
+        Err(reason) => println!("  Part {}: {}{}", part, reason, duration),
+    }
+}
+
+/// How `current` compares with the previous answer to the same part, if there was one.
+pub(crate) fn describe_change(previous: Option<&str>, current: &str) -> String {
+    match previous {
+        None => String::new(),
+        Some(previous) if previous == current => ", unchanged".to_string(),
+        Some(previous) => format!(", changed from {}", previous),
+    }
+}
+
+/// Reads the JSON records the day's binary prints with `--format json`, skipping any other output.
+pub(crate) fn parse_outcomes(stdout: &str) -> Vec<PartOutcome> {
+    stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .map(|record| {
+            let result = match (&record["status"], &record["answer"]) {
+                (Value::String(status), Value::Number(answer)) if status == "ok" => {
+                    Ok(answer.to_string())
+                }
+                (Value::String(status), Value::String(answer)) if status == "ok" => {
+                    Ok(answer.clone())
+                }
+                (Value::String(status), _) if status == "error" || status == "did_not_finish" => {
+                    Err(format!(
+                        "{}: {}",
+                        status.replace('_', " "),
+                        record["error"].as_str().unwrap_or("unknown")
+                    ))
+                }
+                (Value::String(status), _) => Err(status.replace('_', " ")),
+                _ => Err("malformed record".to_string()),
+            };
+
+            PartOutcome {
+                part: record["part"].as_u64().map(|part| part as u8),
+                result,
+                duration: record["duration_ns"].as_u64().map(Duration::from_nanos),
+            }
+        })
+        .collect()
+}
+
+/// The modification times of every file under `paths`, skipping build output and hidden
+/// directories. Paths that don't exist are left out.
+pub(crate) fn take_snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+
+    for path in paths {
+        // A file that disappears mid-walk shows up as removed in the next snapshot instead.
+        let _ = add_to_snapshot(path, &mut snapshot);
+    }
+
+    snapshot
+}
+
+fn add_to_snapshot(path: &Path, snapshot: &mut Snapshot) -> io::Result<()> {
+    let metadata = fs::metadata(path)?;
+
+    if !metadata.is_dir() {
+        snapshot.insert(path.to_path_buf(), metadata.modified()?);
+        return Ok(());
+    }
+
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let name = entry.file_name();
+
+        if name == "target" || name.to_string_lossy().starts_with('.') {
+            continue;
+        }
+
+        let _ = add_to_snapshot(&entry.path(), snapshot);
+    }
+
+    Ok(())
+}
+
+/// Every path added, removed or modified between two snapshots, in order.
+pub(crate) fn changed_paths(previous: &Snapshot, current: &Snapshot) -> Vec<PathBuf> {
+    let mut changed = current
+        .iter()
+        .filter(|(path, modified)| previous.get(*path) != Some(modified))
+        .map(|(path, _)| path.clone())
+        .chain(
+            previous
+                .keys()
+                .filter(|path| !current.contains_key(*path))
+                .cloned(),
+        )
+        .collect::<Vec<_>>();
+
+    changed.sort();
+    changed
+}
+
+fn cargo(args: &[&str]) -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.args(args).current_dir(workspace_root());
+    command
+}
+
+fn part_argument(part: PartSelection) -> &'static str {
+    match part {
+        PartSelection::One => "1",
+        PartSelection::Two => "2",
+        PartSelection::Both => "both",
+    }
+}
+
+/// `path` relative to the workspace root, when it is inside it.
+fn display_path(path: &Path) -> String {
+    let root = workspace_root();
+
+    path.strip_prefix(&root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn changed_paths_works() {
+        let time = |seconds| SystemTime::UNIX_EPOCH + Duration::from_secs(seconds);
+
+        let previous = Snapshot::from([
+            (PathBuf::from("day-1/src/lib.rs"), time(1)),
+            (PathBuf::from("day-1/src/main.rs"), time(1)),
+            (PathBuf::from("day-1/Cargo.toml"), time(1)),
+        ]);
+        let current = Snapshot::from([
+            (PathBuf::from("day-1/src/lib.rs"), time(2)),
+            (PathBuf::from("day-1/src/main.rs"), time(1)),
+            (PathBuf::from("day-1/src/aoc-input/input.txt"), time(2)),
+        ]);
+
+        assert_eq!(
+            changed_paths(&previous, &current),
+            vec![
+                PathBuf::from("day-1/Cargo.toml"),
+                PathBuf::from("day-1/src/aoc-input/input.txt"),
+                PathBuf::from("day-1/src/lib.rs"),
+            ]
+        );
+        assert!(changed_paths(&current, &current).is_empty());
+    }
+
+    #[test]
+    fn parse_outcomes_works() {
+        let stdout = concat!(
+            r#"{"day":17,"part":1,"input":"in.txt","status":"ok","answer":"4,6,3","duration_ns":1500,"error":null}"#,
+            "\n",
+            r#"{"day":17,"part":2,"input":"in.txt","status":"not_found","answer":null,"duration_ns":20,"error":null}"#,
+            "\n",
+            "not json\n",
+            r#"{"day":1,"part":null,"input":"in.txt","status":"error","answer":null,"duration_ns":null,"error":"could not parse input"}"#,
+        );
+
+        assert_eq!(
+            parse_outcomes(stdout),
+            vec![
+                PartOutcome {
+                    part: Some(1),
+                    result: Ok("4,6,3".to_string()),
+                    duration: Some(Duration::from_nanos(1500)),
+                },
+                PartOutcome {
+                    part: Some(2),
+                    result: Err("not found".to_string()),
+                    duration: Some(Duration::from_nanos(20)),
+                },
+                PartOutcome {
+                    part: None,
+                    result: Err("error: could not parse input".to_string()),
+                    duration: None,
+                },
+            ]
+        );
+        assert_eq!(
+            parse_outcomes(
+                r#"{"part":2,"status":"did_not_finish","answer":null,"error":"timed out after 1.00s"}"#
+            )[0]
+            .result,
+            Err("did not finish: timed out after 1.00s".to_string())
+        );
+        assert_eq!(
+            parse_outcomes(r#"{"part":1,"status":"ok","answer":11,"duration_ns":5}"#)[0].result,
+            Ok("11".to_string())
+        );
+    }
+
+    #[test]
+    fn describe_change_works() {
+        assert_eq!(describe_change(None, "11"), "");
+        assert_eq!(describe_change(Some("11"), "11"), ", unchanged");
+        assert_eq!(describe_change(Some("11"), "12"), ", changed from 11");
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt::{self, Display};
+
+use serde::Serialize;
+
+/// The answer to one part of a puzzle, independent of the type each day happens to return.
+///
+/// Serializes as a plain JSON number or string, or `null` when there is no answer.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(u64),
+    Text(String),
+    NotFound,
+    Unsolved,
+    /// The part gave up, because it ran out of time or steps, for the given reason.
+    DidNotFinish(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::NotFound => write!(f, "no answer found"),
+            Answer::Unsolved => write!(f, "not implemented"),
+            Answer::DidNotFinish(reason) => write!(f, "did not finish: {}", reason),
+        }
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value.try_into().expect("answers should fit in a u64"))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+impl<T> From<Option<T>> for Answer
+where
+    T: Into<Answer>,
+{
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => value.into(),
+            None => Answer::NotFound,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn answer_from_works() {
+        assert_eq!(Answer::from(31usize), Answer::Number(31));
+        assert_eq!(Answer::from(Some(7u16)), Answer::Number(7));
+        assert_eq!(Answer::from(None::<u16>), Answer::NotFound);
+        assert_eq!(
+            Answer::from(String::from("4,6,3")),
+            Answer::Text(String::from("4,6,3"))
+        );
+    }
+
+    #[test]
+    fn answer_display_works() {
+        assert_eq!(Answer::Number(31).to_string(), "31");
+        assert_eq!(Answer::Text(String::from("4,6,3")).to_string(), "4,6,3");
+    }
+}
@@ -0,0 +1,226 @@
+use std::{
+    any::Any,
+    fmt::{self, Display},
+    fs, io,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+};
+
+use crate::solution::{AnyParseError, Day, Part, PartRun, PartSelection, Run};
+
+/// Why one input in a batch could not be solved.
+#[derive(Debug)]
+pub enum BatchError {
+    Read(io::Error),
+    Parse(AnyParseError),
+    Panic(String),
+}
+
+impl Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BatchError::Read(error) => write!(f, "could not read input: {}", error),
+            BatchError::Parse(error) => write!(f, "could not parse input: {}", error),
+            BatchError::Panic(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+/// One input file from a batch and how solving it went.
+#[derive(Debug)]
+pub struct BatchEntry {
+    pub input: PathBuf,
+    pub result: Result<Run, BatchError>,
+}
+
+/// The files in `dir`, in name order, leaving out subdirectories and hidden files.
+pub fn input_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+
+        if entry.file_type()?.is_file() && !entry.file_name().to_string_lossy().starts_with('.') {
+            files.push(entry.path());
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+/// Solves the selected parts of `day` for every file, carrying on past files that can't be read
+/// or parsed and past panics, which are caught and reported in the file's entry. The panic hook is
+/// left alone, as it is shared by every thread, so the usual panic message still goes to stderr.
+pub fn run_batch(day: &Day, files: &[PathBuf], selection: PartSelection) -> Vec<BatchEntry> {
+    files
+        .iter()
+        .map(|input| BatchEntry {
+            input: input.clone(),
+            result: solve_file(day, input, selection),
+        })
+        .collect()
+}
+
+fn solve_file(day: &Day, path: &Path, selection: PartSelection) -> Result<Run, BatchError> {
+    let input = fs::read_to_string(path).map_err(BatchError::Read)?;
+
+    panic::catch_unwind(AssertUnwindSafe(|| (day.solve)(&input, selection)))
+        .map_err(|payload| BatchError::Panic(panic_message(payload.as_ref())))?
+        .map_err(BatchError::Parse)
+}
+
+/// The message a panic was raised with, when it was a string.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+/// Prints a row per input with the parse time, each part's answer and time, and whether it was
+/// solved, with the columns lined up.
+pub fn print_table(entries: &[BatchEntry], parts: &[Part]) {
+    for line in table(entries, parts) {
+        println!("{}", line);
+    }
+}
+
+fn table(entries: &[BatchEntry], parts: &[Part]) -> Vec<String> {
+    let mut header = vec![String::from("Input"), String::from("Parse")];
+    for part in parts {
+        header.push(format!("Part {}", part));
+        header.push(String::from("Time"));
+    }
+    header.push(String::from("Status"));
+
+    let rows = entries.iter().map(|entry| {
+        let mut row = vec![entry.input.display().to_string()];
+
+        match &entry.result {
+            Ok(run) => {
+                row.push(format!("{:.2?}", run.parse_duration));
+                for part_run in &run.parts {
+                    row.push(part_run.answer.to_string());
+                    row.push(format!("{:.2?}", part_run.duration));
+                }
+                row.push(String::from(
+                    if run.parts.iter().any(PartRun::did_not_finish) {
+                        "did not finish"
+                    } else {
+                        "ok"
+                    },
+                ));
+            }
+            Err(error) => {
+                row.resize(header.len() - 1, String::new());
+                row.push(error.to_string());
+            }
+        }
+
+        row
+    });
+
+    let cells = std::iter::once(header.clone())
+        .chain(rows)
+        .collect::<Vec<_>>();
+
+    let widths = (0..header.len())
+        .map(|column| {
+            cells
+                .iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    cells
+        .iter()
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, &width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{num::ParseIntError, process};
+
+    use super::*;
+    use crate::{Answer, Solution};
+
+    struct Shares;
+
+    impl Solution for Shares {
+        const DAY: u8 = 0;
+
+        type Input = Vec<usize>;
+        type ParseError = ParseIntError;
+        type PartOne = usize;
+        type PartTwo = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+            input.split(',').map(|n| n.trim().parse()).collect()
+        }
+
+        fn part_one(input: &Self::Input) -> Self::PartOne {
+            input.iter().map(|n| 100 / n).sum()
+        }
+
+        fn part_two(input: &Self::Input) -> Self::PartTwo {
+            input.len()
+        }
+    }
+
+    #[test]
+    fn run_batch_works() {
+        let dir = std::env::temp_dir().join(format!("aoc-batch-test-{}", process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("a.txt"), "1,2").unwrap();
+        fs::write(dir.join("b.txt"), "1,x").unwrap();
+        fs::write(dir.join("c.txt"), "0,2").unwrap();
+        fs::write(dir.join(".hidden"), "1").unwrap();
+
+        let files = input_files(&dir).unwrap();
+        let entries = run_batch(&Day::of::<Shares>(), &files, PartSelection::One);
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            files,
+            ["a.txt", "b.txt", "c.txt"].map(|name| dir.join(name))
+        );
+
+        assert_eq!(
+            entries[0].result.as_ref().unwrap().parts[0].answer,
+            Answer::Number(150)
+        );
+        assert_eq!(
+            entries[1].result.as_ref().unwrap_err().to_string(),
+            "could not parse input: invalid digit found in string"
+        );
+        assert_eq!(
+            entries[2].result.as_ref().unwrap_err().to_string(),
+            "panicked: attempt to divide by zero"
+        );
+
+        let table = table(&entries, &[Part::One]);
+        assert!(table[0].starts_with("Input"));
+        assert!(table[0].contains("  Parse"));
+        assert!(table[0].ends_with("  Status"));
+        assert!(table[1].contains("  150  "));
+        assert!(table[1].ends_with("  ok"));
+        assert!(table[2].ends_with("  could not parse input: invalid digit found in string"));
+        assert!(table[3].ends_with("  panicked: attempt to divide by zero"));
+    }
+}
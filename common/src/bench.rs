@@ -0,0 +1,122 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answer::Answer,
+    solution::{Part, Solution},
+};
+
+/// Summary statistics over the timings of repeated runs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+}
+
+impl Stats {
+    /// Panics if `samples` is empty.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is needed");
+
+        let mut samples = samples.to_vec();
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Self {
+            mean: samples.iter().sum::<Duration>() / samples.len() as u32,
+            median,
+            min: samples[0],
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Bench {
+    pub iterations: usize,
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+}
+
+/// Times parsing and each of `parts` separately, `iterations` times over.
+pub fn bench<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<Bench, S::ParseError> {
+    let iterations = iterations.max(1);
+
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut parsed_input = S::parse(input)?;
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        parsed_input = black_box(S::parse(black_box(input))?);
+        parse_samples.push(start.elapsed());
+    }
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let samples = (0..iterations)
+                .map(|_| {
+                    let start = Instant::now();
+                    let answer: Answer = match part {
+                        Part::One => S::part_one(black_box(&parsed_input)).into(),
+                        Part::Two => S::part_two(black_box(&parsed_input)).into(),
+                    };
+                    black_box(answer);
+                    start.elapsed()
+                })
+                .collect::<Vec<_>>();
+
+            (part, Stats::from_samples(&samples))
+        })
+        .collect();
+
+    Ok(Bench {
+        iterations,
+        parse: Stats::from_samples(&parse_samples),
+        parts,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn stats_from_samples_works() {
+        let millis = |values: &[u64]| {
+            values
+                .iter()
+                .map(|&value| Duration::from_millis(value))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            Stats::from_samples(&millis(&[5, 1, 3])),
+            Stats {
+                mean: Duration::from_millis(3),
+                median: Duration::from_millis(3),
+                min: Duration::from_millis(1),
+            }
+        );
+        assert_eq!(
+            Stats::from_samples(&millis(&[4, 1, 2, 9])),
+            Stats {
+                mean: Duration::from_millis(4),
+                median: Duration::from_millis(3),
+                min: Duration::from_millis(1),
+            }
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    panic,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answer::Answer,
+    solution::{PartRun, PartSelection, Progress, Report, Run},
+};
+
+/// Why a part gave up before reaching an answer, such as running out of its step budget, with
+/// whatever it had worked out by then.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DidNotFinish {
+    pub reason: String,
+}
+
+impl DidNotFinish {
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+}
+
+impl Display for DidNotFinish {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.reason)
+    }
+}
+
+impl Error for DidNotFinish {}
+
+impl<T> From<Result<T, DidNotFinish>> for Answer
+where
+    T: Into<Answer>,
+{
+    fn from(value: Result<T, DidNotFinish>) -> Self {
+        match value {
+            Ok(value) => value.into(),
+            Err(error) => Answer::DidNotFinish(error.reason),
+        }
+    }
+}
+
+/// Runs `solve` on its own thread, waiting at most `timeout` for it. The parts that finish in time
+/// keep their answers and the rest are answered with [`Answer::DidNotFinish`].
+///
+/// A solver that times out is left running, as threads can't be stopped from outside, so the
+/// caller should exit soon after. A panic in the solver is resumed on the calling thread.
+pub fn solve_within<E>(
+    timeout: Duration,
+    selection: PartSelection,
+    solve: impl FnOnce(Report) -> Result<Run, E> + Send + 'static,
+) -> Result<Run, E>
+where
+    E: Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    let start = Instant::now();
+
+    let progress_sender = sender.clone();
+    let solver = thread::spawn(move || {
+        let result = solve(&mut |progress| {
+            let _ = progress_sender.send(Ok(progress));
+        });
+        let _ = sender.send(Err(result));
+    });
+
+    let mut parse_duration = None;
+    let mut parts = Vec::new();
+
+    loop {
+        match receiver.recv_timeout(timeout.saturating_sub(start.elapsed())) {
+            Ok(Ok(Progress::Parsed(duration))) => parse_duration = Some(duration),
+            Ok(Ok(Progress::Solved(part_run))) => parts.push(part_run),
+            Ok(Err(result)) => return result,
+            Err(RecvTimeoutError::Timeout) => break,
+            Err(RecvTimeoutError::Disconnected) => match solver.join() {
+                Err(payload) => panic::resume_unwind(payload),
+                Ok(()) => unreachable!("the solver sends its result before it finishes"),
+            },
+        }
+    }
+
+    let elapsed = start.elapsed();
+    let reason = match parse_duration {
+        Some(_) => format!("timed out after {:.2?}", timeout),
+        None => format!("timed out after {:.2?} while parsing", timeout),
+    };
+
+    for part in selection.parts() {
+        if parts.iter().all(|part_run| part_run.part != part) {
+            let duration = elapsed
+                .saturating_sub(parse_duration.unwrap_or(elapsed))
+                .saturating_sub(parts.iter().map(|part_run| part_run.duration).sum());
+
+            parts.push(PartRun {
+                part,
+                answer: Answer::DidNotFinish(reason.clone()),
+                duration,
+            });
+        }
+    }
+
+    Ok(Run {
+        parse_duration: parse_duration.unwrap_or(elapsed),
+        parts,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::Part;
+
+    #[test]
+    fn answer_from_result_works() {
+        assert_eq!(Answer::from(Ok::<u16, _>(7)), Answer::Number(7));
+        assert_eq!(
+            Answer::from(Err::<u16, _>(DidNotFinish::new("stuck in a loop"))),
+            Answer::DidNotFinish(String::from("stuck in a loop"))
+        );
+        assert_eq!(
+            Answer::DidNotFinish(String::from("stuck in a loop")).to_string(),
+            "did not finish: stuck in a loop"
+        );
+    }
+
+    #[test]
+    fn solve_within_works() {
+        let timeout = Duration::from_millis(50);
+
+        let run = solve_within::<()>(timeout, PartSelection::Both, |report| {
+            report(Progress::Parsed(Duration::from_millis(1)));
+            let part_run = PartRun {
+                part: Part::One,
+                answer: Answer::Number(3),
+                duration: Duration::from_millis(2),
+            };
+            report(Progress::Solved(part_run.clone()));
+
+            Ok(Run {
+                parse_duration: Duration::from_millis(1),
+                parts: vec![part_run],
+            })
+        })
+        .unwrap();
+
+        assert_eq!(run.parts.len(), 1);
+        assert_eq!(run.parts[0].answer, Answer::Number(3));
+
+        let run = solve_within::<()>(timeout, PartSelection::Both, |report| {
+            report(Progress::Parsed(Duration::from_millis(1)));
+            report(Progress::Solved(PartRun {
+                part: Part::One,
+                answer: Answer::Number(3),
+                duration: Duration::from_millis(2),
+            }));
+
+            thread::sleep(Duration::from_secs(60));
+            Err(())
+        })
+        .unwrap();
+
+        assert_eq!(run.parse_duration, Duration::from_millis(1));
+        assert_eq!(
+            run.parts
+                .iter()
+                .map(|part_run| (part_run.part, part_run.answer.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Part::One, Answer::Number(3)),
+                (
+                    Part::Two,
+                    Answer::DidNotFinish(String::from("timed out after 50.00ms"))
+                )
+            ]
+        );
+
+        assert_eq!(
+            solve_within(timeout, PartSelection::One, |_| Err::<Run, _>("unparsable")).unwrap_err(),
+            "unparsable"
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt::{self, Display};
+
+use crate::parse::{Located, Location};
+
+/// One way an input breaks an assumption its day's solution makes.
+#[derive(Debug, PartialEq)]
+pub struct Violation {
+    /// Where in the input, unless it is about the input as a whole.
+    pub location: Option<Location>,
+    pub message: String,
+}
+
+impl Violation {
+    pub fn at(location: Location, message: impl Display) -> Self {
+        Self {
+            location: Some(location),
+            message: message.to_string(),
+        }
+    }
+
+    /// A violation of the input as a whole, such as something it is missing.
+    pub fn anywhere(message: impl Display) -> Self {
+        Self {
+            location: None,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl<E: Display> From<Located<E>> for Violation {
+    fn from(error: Located<E>) -> Self {
+        Self::at(error.location, error.error)
+    }
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{}: {}", location, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Parses every line of `input` on its own, collecting each line's error rather than stopping at
+/// the first.
+pub fn check_lines<T, E: Display>(
+    input: &str,
+    mut parse_line: impl FnMut(&str) -> Result<T, Located<E>>,
+) -> Vec<Violation> {
+    input
+        .lines()
+        .filter_map(|line| parse_line(line).err())
+        .map(Violation::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_lines_works() {
+        let input = "1\nx\n3\ny";
+
+        let violations = check_lines(input, |line| {
+            line.parse::<usize>()
+                .map_err(|_| Located::at(input, line, "not a number"))
+        });
+
+        assert_eq!(
+            violations,
+            vec![
+                Violation::at(Location::new(2, 1), "not a number"),
+                Violation::at(Location::new(4, 1), "not a number"),
+            ]
+        );
+        assert_eq!(violations[0].to_string(), "line 2, column 1: not a number");
+        assert_eq!(Violation::anywhere("empty").to_string(), "empty");
+    }
+}
@@ -0,0 +1,389 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    process,
+    time::Duration,
+};
+
+use clap::{ArgAction, Parser};
+
+use crate::{
+    answer::Answer,
+    batch::{input_files, print_table, run_batch},
+    budget::solve_within,
+    input::{InputSource, StreamError},
+    logging,
+    pool::{self, THREADS_ENV_VAR},
+    report::{Format, Record},
+    solution::{
+        solve_reporting, solve_stream, AnyParseError, Day, PartRun, PartSelection, Report, Run,
+        Solution, StreamingSolution,
+    },
+};
+
+/// Runs the calling day's binary, solving the parts selected with `--part`.
+#[macro_export]
+macro_rules! run {
+    ($solution:ty) => {
+        $crate::cli::run::<$solution>($crate::default_input_path!())
+    };
+}
+
+/// Like [`run!`], for days that can also solve their input in one pass with `--stream`.
+#[macro_export]
+macro_rules! run_streaming {
+    ($solution:ty) => {
+        $crate::cli::run_streaming::<$solution>($crate::default_input_path!())
+    };
+}
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Path to the puzzle input, or `-` to read it from stdin.
+    #[arg(long)]
+    pub input: Option<PathBuf>,
+
+    /// Which part of the puzzle to solve.
+    #[arg(long, value_enum, default_value_t = PartSelection::Both)]
+    pub part: PartSelection,
+
+    /// How to print the answers.
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// Threads for parallel searches. Defaults to one per CPU.
+    #[arg(long, env = THREADS_ENV_VAR)]
+    pub threads: Option<NonZeroUsize>,
+
+    /// Check the input against the assumptions the solution makes, listing every problem found,
+    /// instead of solving it.
+    #[arg(long)]
+    pub check: bool,
+
+    /// Give up on any part still running after this many seconds, reporting it as not finished
+    /// along with the answers found so far.
+    #[arg(long, value_parser = parse_seconds)]
+    pub timeout: Option<Duration>,
+
+    /// Log progress to stderr. Repeat for more detail: `-vv` for debug output, `-vvv` for
+    /// traces of every step.
+    #[arg(short, long, action = ArgAction::Count)]
+    pub verbose: u8,
+}
+
+#[derive(Debug, Parser)]
+pub struct StreamingArgs {
+    #[command(flatten)]
+    pub args: Args,
+
+    /// Solve the input a record at a time as it is read, instead of reading it all first, for
+    /// inputs too large to fit in memory.
+    #[arg(long)]
+    pub stream: bool,
+}
+
+/// Parses a number of seconds, such as `5` or `0.5`, into a [`Duration`].
+pub fn parse_seconds(seconds: &str) -> Result<Duration, String> {
+    let seconds = seconds
+        .parse::<f64>()
+        .map_err(|error| format!("{}: expected a number of seconds", error))?;
+
+    Duration::try_from_secs_f64(seconds).map_err(|error| error.to_string())
+}
+
+/// Solves the selected parts of an input, passing each stage to the reporter as it finishes.
+type Solver = fn(&InputSource, PartSelection, Report) -> Result<Run, RunError>;
+
+/// Why a day could not be solved from its input.
+enum RunError {
+    Read(io::Error),
+    Parse(AnyParseError),
+}
+
+impl RunError {
+    fn from_stream<E: Error + Send + Sync + 'static>(error: StreamError<E>) -> Self {
+        match error {
+            StreamError::Read(error) => RunError::Read(error),
+            StreamError::Parse(error) => RunError::Parse(Box::new(error)),
+        }
+    }
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Read(error) => write!(f, "could not read input: {}", error),
+            RunError::Parse(error) => write!(f, "could not parse input: {}", error),
+        }
+    }
+}
+
+pub fn run<S: Solution>(default_input_path: &Path) {
+    run_with::<S>(Args::parse(), default_input_path, solve_in_memory::<S>);
+}
+
+pub fn run_streaming<S: StreamingSolution>(default_input_path: &Path) {
+    let StreamingArgs { args, stream } = StreamingArgs::parse();
+
+    if stream {
+        run_with::<S>(args, default_input_path, solve_streamed::<S>);
+    } else {
+        run_with::<S>(args, default_input_path, solve_in_memory::<S>);
+    }
+}
+
+fn solve_in_memory<S: Solution>(
+    source: &InputSource,
+    selection: PartSelection,
+    report: Report,
+) -> Result<Run, RunError> {
+    let input = source.read().map_err(RunError::Read)?;
+
+    solve_reporting::<S>(&input, selection, report)
+        .map_err(|error| RunError::Parse(Box::new(error)))
+}
+
+/// Both parts are only known once the whole input has been read, so there is no progress to
+/// report along the way.
+fn solve_streamed<S: StreamingSolution>(
+    source: &InputSource,
+    selection: PartSelection,
+    _report: Report,
+) -> Result<Run, RunError> {
+    let reader = source.open().map_err(RunError::Read)?;
+
+    solve_stream::<S>(reader, selection).map_err(RunError::from_stream)
+}
+
+fn run_with<S: Solution>(args: Args, default_input_path: &Path, solve: Solver) {
+    logging::init(args.verbose);
+
+    if let Some(threads) = args.threads {
+        pool::set_threads(threads);
+    }
+
+    let source = InputSource::from_arg(args.input, default_input_path);
+
+    if args.check {
+        run_check::<S>(&source);
+        return;
+    }
+
+    if let InputSource::File(path) = &source {
+        if path.is_dir() {
+            if args.timeout.is_some() {
+                eprintln!("error: --timeout can't be used with a directory of inputs");
+                process::exit(1);
+            }
+
+            run_directory::<S>(path, args.part, args.format);
+            return;
+        }
+    }
+
+    let solve = |source: InputSource, selection| match args.timeout {
+        Some(timeout) => solve_within(timeout, selection, move |report| {
+            solve(&source, selection, report)
+        }),
+        None => solve(&source, selection, &mut |_| {}),
+    };
+
+    match args.format {
+        Format::Text => run_text(source, args.part, solve),
+        Format::Json => run_json::<S>(source, args.part, solve),
+    }
+}
+
+fn run_check<S: Solution>(source: &InputSource) {
+    let input = source.read().unwrap_or_else(|error| {
+        eprintln!(
+            "error: could not read input from {}: {}",
+            source.describe(),
+            error
+        );
+        process::exit(1);
+    });
+
+    let violations = S::check(&input);
+
+    for violation in &violations {
+        println!("{}", violation);
+    }
+
+    if !violations.is_empty() {
+        eprintln!(
+            "error: {} has {} problem(s) for day {}",
+            source.describe(),
+            violations.len(),
+            S::DAY
+        );
+        process::exit(1);
+    }
+
+    println!("{} looks valid for day {}", source.describe(), S::DAY);
+}
+
+/// Solves every file in `dir` in memory, printing a table of the answers and timings, or a
+/// [`Record`] per file and part. Exits non-zero if any file could not be solved.
+fn run_directory<S: Solution>(dir: &Path, part: PartSelection, format: Format) {
+    let files = input_files(dir).unwrap_or_else(|error| {
+        eprintln!(
+            "error: could not list inputs in {}: {}",
+            dir.display(),
+            error
+        );
+        process::exit(1);
+    });
+
+    let Some(selection) = part.excluding(S::INTERACTIVE_PARTS) else {
+        eprintln!("error: interactive parts can't be solved for a directory of inputs");
+        process::exit(1);
+    };
+
+    let entries = run_batch(&Day::of::<S>(), &files, selection);
+
+    match format {
+        Format::Text => print_table(&entries, &selection.parts()),
+        Format::Json => {
+            for entry in &entries {
+                let input_name = entry.input.display().to_string();
+
+                match &entry.result {
+                    Ok(run) => {
+                        for part_run in &run.parts {
+                            println!(
+                                "{}",
+                                Record::solved(S::DAY, &input_name, part_run).to_json()
+                            );
+                        }
+                    }
+                    Err(error) => {
+                        println!("{}", Record::error(S::DAY, &input_name, error).to_json());
+                    }
+                }
+            }
+        }
+    }
+
+    if entries.iter().any(|entry| match &entry.result {
+        Ok(run) => run.parts.iter().any(PartRun::did_not_finish),
+        Err(_) => true,
+    }) {
+        process::exit(1);
+    }
+}
+
+fn run_text(
+    source: InputSource,
+    part: PartSelection,
+    solve: impl FnOnce(InputSource, PartSelection) -> Result<Run, RunError>,
+) {
+    let input_name = source.describe();
+
+    let run = match solve(source, part) {
+        Ok(run) => run,
+        Err(error) => {
+            let (action, cause): (&str, &dyn Display) = match &error {
+                RunError::Read(error) => ("read", error),
+                RunError::Parse(error) => ("parse", error),
+            };
+
+            eprintln!(
+                "error: could not {} input from {}: {}",
+                action, input_name, cause
+            );
+            process::exit(1);
+        }
+    };
+
+    for part_run in &run.parts {
+        if part_run.answer == Answer::Unsolved && part != PartSelection::Both {
+            eprintln!(
+                "error: part {} is not implemented for this day",
+                part_run.part
+            );
+            process::exit(1);
+        }
+
+        println!("Part {}: {}", part_run.part, part_run.answer);
+    }
+
+    if run.parts.iter().any(PartRun::did_not_finish) {
+        process::exit(1);
+    }
+}
+
+/// Prints one [`Record`] per line. Interactive parts are skipped, as their prompts would end up
+/// mixed in with the JSON.
+fn run_json<S: Solution>(
+    source: InputSource,
+    part: PartSelection,
+    solve: impl FnOnce(InputSource, PartSelection) -> Result<Run, RunError>,
+) {
+    let input_name = source.describe();
+
+    let part_runs = match part.excluding(S::INTERACTIVE_PARTS) {
+        Some(selection) => solve(source, selection).map(|run| run.parts),
+        None => Ok(Vec::new()),
+    };
+
+    let records = part_runs
+        .map(|part_runs| {
+            part.parts()
+                .into_iter()
+                .map(|part| match part_runs.iter().find(|run| run.part == part) {
+                    Some(part_run) => Record::solved(S::DAY, &input_name, part_run),
+                    None => Record::skipped(S::DAY, &input_name, part),
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_else(|error| vec![Record::error(S::DAY, &input_name, error)]);
+
+    for record in &records {
+        println!("{}", record.to_json());
+    }
+
+    if records.iter().any(|record| record.error.is_some()) {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn args_part_works() {
+        let args = Args::parse_from(["day-1"]);
+        assert_eq!(args.part, PartSelection::Both);
+
+        let args = Args::parse_from(["day-1", "--part", "1"]);
+        assert_eq!(args.part, PartSelection::One);
+
+        let args = Args::parse_from(["day-1", "--part", "2"]);
+        assert_eq!(args.part, PartSelection::Two);
+
+        assert!(Args::try_parse_from(["day-1", "--part", "3"]).is_err());
+
+        let args = Args::parse_from(["day-1", "--format", "json"]);
+        assert_eq!(args.format, Format::Json);
+
+        assert!(!args.check);
+        assert!(Args::parse_from(["day-1", "--check"]).check);
+
+        assert_eq!(args.timeout, None);
+        assert_eq!(
+            Args::parse_from(["day-1", "--timeout", "2.5"]).timeout,
+            Some(Duration::from_millis(2500))
+        );
+        assert!(Args::try_parse_from(["day-1", "--timeout", "-1"]).is_err());
+        assert!(Args::try_parse_from(["day-1", "--timeout", "soon"]).is_err());
+
+        assert_eq!(args.verbose, 0);
+        assert_eq!(Args::parse_from(["day-1", "-vv"]).verbose, 2);
+        assert_eq!(Args::parse_from(["day-1", "-v", "--verbose"]).verbose, 2);
+    }
+}
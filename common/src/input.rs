@@ -0,0 +1,176 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::parse::Located;
+
+/// Expands to the calling day's default input path, `src/aoc-input/input.txt`.
+#[macro_export]
+macro_rules! default_input_path {
+    () => {
+        ::std::path::Path::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/aoc-input/input.txt"
+        ))
+    };
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn from_arg(input: Option<PathBuf>, default_path: &Path) -> Self {
+        match input {
+            Some(path) if path.as_os_str() == "-" => Self::Stdin,
+            Some(path) => Self::File(path),
+            None => Self::File(default_path.to_path_buf()),
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Self::File(path) => fs::read_to_string(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// Opens the input for reading a bit at a time, rather than all at once.
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Self::File(path) => Ok(Box::new(BufReader::new(File::open(path)?))),
+            Self::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Self::File(path) => path.display().to_string(),
+            Self::Stdin => String::from("stdin"),
+        }
+    }
+}
+
+/// Why an input read a record at a time could not be solved.
+#[derive(Debug)]
+pub enum StreamError<E> {
+    Read(io::Error),
+    Parse(Located<E>),
+}
+
+impl<E: Display> Display for StreamError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Read(error) => write!(f, "{}", error),
+            StreamError::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for StreamError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StreamError::Read(error) => Some(error),
+            StreamError::Parse(error) => Some(error),
+        }
+    }
+}
+
+impl<E> From<io::Error> for StreamError<E> {
+    fn from(error: io::Error) -> Self {
+        StreamError::Read(error)
+    }
+}
+
+impl<E> From<Located<E>> for StreamError<E> {
+    fn from(error: Located<E>) -> Self {
+        StreamError::Parse(error)
+    }
+}
+
+/// Hands each line of `reader` to `record`, without its line ending, and returns how many lines
+/// there were. Only one line is held in memory at a time. Errors that `record` locates within its
+/// line are moved to that line of the input.
+pub fn for_each_line<E>(
+    mut reader: impl BufRead,
+    mut record: impl FnMut(&str) -> Result<(), Located<E>>,
+) -> Result<usize, StreamError<E>> {
+    let mut line = String::new();
+    let mut line_number = 0;
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(line_number);
+        }
+
+        line_number += 1;
+
+        let line = line.strip_suffix('\n').unwrap_or(&line);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        record(line).map_err(|error| error.on_line(line_number))?;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse::Location;
+
+    #[test]
+    fn input_source_from_arg_works() {
+        let default_path = Path::new("aoc-input/input.txt");
+
+        assert_eq!(
+            InputSource::from_arg(None, default_path),
+            InputSource::File(PathBuf::from("aoc-input/input.txt"))
+        );
+        assert_eq!(
+            InputSource::from_arg(Some(PathBuf::from("other.txt")), default_path),
+            InputSource::File(PathBuf::from("other.txt"))
+        );
+        assert_eq!(
+            InputSource::from_arg(Some(PathBuf::from("-")), default_path),
+            InputSource::Stdin
+        );
+    }
+
+    #[test]
+    fn for_each_line_works() {
+        let mut lines = Vec::new();
+
+        let line_count = for_each_line("a\r\nbb\n\nc".as_bytes(), |line| {
+            lines.push(line.to_string());
+            Ok::<_, Located<()>>(())
+        })
+        .unwrap();
+
+        assert_eq!(line_count, 4);
+        assert_eq!(lines, vec!["a", "bb", "", "c"]);
+
+        let error = for_each_line("ok\nok\nbad line".as_bytes(), |line| match line {
+            "ok" => Ok(()),
+            _ => Err(Located::at(line, &line[4..], "bad")),
+        })
+        .unwrap_err();
+
+        assert!(matches!(
+            error,
+            StreamError::Parse(Located {
+                location: Location { line: 3, column: 5 },
+                error: "bad"
+            })
+        ));
+    }
+}
@@ -0,0 +1,18 @@
+pub mod answer;
+pub mod batch;
+pub mod bench;
+pub mod budget;
+pub mod check;
+pub mod cli;
+pub mod input;
+pub mod logging;
+pub mod parse;
+pub mod pool;
+pub mod report;
+pub mod solution;
+
+pub use answer::Answer;
+pub use budget::DidNotFinish;
+pub use check::Violation;
+pub use parse::{Located, Location};
+pub use solution::{Day, Part, PartSelection, Solution, StreamingSolution};
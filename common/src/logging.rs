@@ -0,0 +1,47 @@
+use std::io::{self, IsTerminal};
+
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::{fmt::time::Uptime, EnvFilter};
+
+/// Environment variable holding a filter such as `info,day_15=trace`, which overrides `-v`.
+pub const LOG_ENV_VAR: &str = "AOC_LOG";
+
+/// The most detailed level logged when `-v` is given `verbosity` times.
+pub fn level(verbosity: u8) -> LevelFilter {
+    match verbosity {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+/// Logs to stderr, so that the answers on stdout stay clean, at the level picked by `verbosity`
+/// unless [`LOG_ENV_VAR`] is set. Does nothing if logging has already been set up.
+pub fn init(verbosity: u8) {
+    let filter = EnvFilter::builder()
+        .with_default_directive(level(verbosity).into())
+        .with_env_var(LOG_ENV_VAR)
+        .from_env_lossy();
+
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_timer(Uptime::default())
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .try_init();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn level_works() {
+        assert_eq!(level(0), LevelFilter::WARN);
+        assert_eq!(level(1), LevelFilter::INFO);
+        assert_eq!(level(2), LevelFilter::DEBUG);
+        assert_eq!(level(3), LevelFilter::TRACE);
+        assert_eq!(level(u8::MAX), LevelFilter::TRACE);
+    }
+}
@@ -0,0 +1,100 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// A 1-based line and column in the puzzle input.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    pub const fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+
+    /// Finds where `text` starts in `input`. `text` has to be a slice of `input`, such as the ones
+    /// handed out by `lines`, `split` and friends.
+    pub fn of(input: &str, text: &str) -> Self {
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .expect("text to be a slice of input");
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        Self::new(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        )
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// A parse error along with where in the puzzle input it was found.
+#[derive(Debug, PartialEq)]
+pub struct Located<E> {
+    pub location: Location,
+    pub error: E,
+}
+
+impl<E> Located<E> {
+    pub fn new(location: Location, error: E) -> Self {
+        Self { location, error }
+    }
+
+    /// Locates `error` at the start of `text`, which has to be a slice of `input`.
+    pub fn at(input: &str, text: &str, error: E) -> Self {
+        Self::new(Location::of(input, text), error)
+    }
+
+    /// Moves an error located within a single line, parsed on its own, to that line's number in
+    /// the whole input.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.location.line += line - 1;
+        self
+    }
+}
+
+impl<E: Display> Display for Located<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.error)
+    }
+}
+
+impl<E: Error + 'static> Error for Located<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn location_of_works() {
+        let input = "ab\ncdé\nfg";
+        let lines = input.lines().collect::<Vec<_>>();
+
+        assert_eq!(Location::of(input, input), Location::new(1, 1));
+        assert_eq!(Location::of(input, lines[1]), Location::new(2, 1));
+        assert_eq!(Location::of(input, &lines[2][1..]), Location::new(3, 2));
+        assert_eq!(
+            Location::of(input, &input[input.len()..]),
+            Location::new(3, 3)
+        );
+        assert_eq!(
+            Location::of(input, &input[input.find('\n').unwrap() + 5..]),
+            Location::new(2, 4)
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use std::{
+    env,
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+/// Environment variable setting how many threads a [`Pool`] uses, unless `--threads` is given.
+pub const THREADS_ENV_VAR: &str = "AOC_THREADS";
+
+/// How many chunks each thread gets, on average, so that threads finishing early can pick up more.
+const CHUNKS_PER_THREAD: usize = 4;
+
+/// Thread count set from the command line, or 0 if it was not given.
+static CONFIGURED_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// Sets the thread count every default [`Pool`] uses, overriding [`THREADS_ENV_VAR`].
+pub fn set_threads(threads: NonZeroUsize) {
+    CONFIGURED_THREADS.store(threads.get(), Ordering::Relaxed);
+}
+
+/// The thread count set with [`set_threads`], else [`THREADS_ENV_VAR`], else one per CPU.
+pub fn threads() -> usize {
+    let configured = CONFIGURED_THREADS.load(Ordering::Relaxed);
+    if configured > 0 {
+        return configured;
+    }
+
+    env::var(THREADS_ENV_VAR)
+        .ok()
+        .and_then(|threads| threads.parse::<NonZeroUsize>().ok())
+        .or_else(|| thread::available_parallelism().ok())
+        .map_or(1, NonZeroUsize::get)
+}
+
+/// Splits work on a slice of items into chunks and spreads them over scoped threads.
+///
+/// The calling thread always works through chunks itself, so a pool of one thread, a single chunk
+/// or a failure to spawn any threads all fall back to running everything sequentially.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pool {
+    threads: usize,
+    chunk_size: Option<usize>,
+}
+
+impl Default for Pool {
+    fn default() -> Self {
+        Self::new(threads())
+    }
+}
+
+impl Pool {
+    pub fn new(threads: usize) -> Self {
+        Self {
+            threads: threads.max(1),
+            chunk_size: None,
+        }
+    }
+
+    /// Hands out `chunk_size` items at a time, instead of sizing chunks from the thread count.
+    pub fn with_chunk_size(self, chunk_size: usize) -> Self {
+        Self {
+            chunk_size: Some(chunk_size.max(1)),
+            ..self
+        }
+    }
+
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    /// Maps every item and combines the results with `reduce`, or returns `None` if there are no
+    /// items. `reduce` is applied in item order, but should not care how the items are grouped.
+    pub fn map_reduce<T, R>(
+        &self,
+        items: &[T],
+        map: impl Fn(&T) -> R + Sync,
+        reduce: impl Fn(R, R) -> R + Sync,
+    ) -> Option<R>
+    where
+        T: Sync,
+        R: Send,
+    {
+        self.map_reduce_with(items, || (), |_, item| map(item), reduce)
+    }
+
+    /// Like [`Pool::map_reduce`], with each thread setting up its own `state` with `init` for
+    /// `map` to reuse, such as a copy of the puzzle input to mutate or a cache.
+    pub fn map_reduce_with<T, S, R>(
+        &self,
+        items: &[T],
+        init: impl Fn() -> S + Sync,
+        map: impl Fn(&mut S, &T) -> R + Sync,
+        reduce: impl Fn(R, R) -> R + Sync,
+    ) -> Option<R>
+    where
+        T: Sync,
+        R: Send,
+    {
+        self.run_chunks(items, init, |state, chunk| {
+            chunk.iter().map(|item| map(state, item)).reduce(&reduce)
+        })
+        .into_iter()
+        .flatten()
+        .reduce(&reduce)
+    }
+
+    /// Maps every item, keeping the results in item order.
+    pub fn map<T, R>(&self, items: &[T], map: impl Fn(&T) -> R + Sync) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+    {
+        self.run_chunks(
+            items,
+            || (),
+            |_, chunk| chunk.iter().map(&map).collect::<Vec<_>>(),
+        )
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Runs `process` on every chunk of `items`, returning its results in chunk order.
+    fn run_chunks<T, S, C>(
+        &self,
+        items: &[T],
+        init: impl Fn() -> S + Sync,
+        process: impl Fn(&mut S, &[T]) -> C + Sync,
+    ) -> Vec<C>
+    where
+        T: Sync,
+        C: Send,
+    {
+        let chunk_size = self
+            .chunk_size
+            .unwrap_or_else(|| items.len().div_ceil(self.threads * CHUNKS_PER_THREAD))
+            .max(1);
+        let chunks = items.chunks(chunk_size).collect::<Vec<_>>();
+
+        if self.threads == 1 || chunks.len() <= 1 {
+            let mut state = init();
+            return chunks
+                .into_iter()
+                .map(|chunk| process(&mut state, chunk))
+                .collect();
+        }
+
+        let next_chunk = AtomicUsize::new(0);
+        let results = Mutex::new(Vec::with_capacity(chunks.len()));
+
+        let work = || {
+            let mut state = None;
+
+            loop {
+                let index = next_chunk.fetch_add(1, Ordering::Relaxed);
+                let Some(chunk) = chunks.get(index) else {
+                    break;
+                };
+
+                let state = state.get_or_insert_with(&init);
+                let result = process(state, chunk);
+
+                results
+                    .lock()
+                    .expect("no worker to have panicked while holding the results")
+                    .push((index, result));
+            }
+        };
+
+        thread::scope(|scope| {
+            for _ in 1..self.threads.min(chunks.len()) {
+                // Whatever the spawned threads do not get to, the calling thread does below.
+                if thread::Builder::new().spawn_scoped(scope, work).is_err() {
+                    break;
+                }
+            }
+
+            work();
+        });
+
+        let mut results = results
+            .into_inner()
+            .expect("no worker to have panicked while holding the results");
+        results.sort_by_key(|(index, _)| *index);
+
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn map_reduce_works() {
+        let items = (1..=1000).collect::<Vec<usize>>();
+
+        for threads in [1, 2, 7] {
+            let pool = Pool::new(threads);
+
+            assert_eq!(
+                pool.map_reduce(&items, |n| n * 2, |a, b| a + b),
+                Some(1001000)
+            );
+            assert_eq!(pool.map_reduce(&[] as &[usize], |n| *n, |a, b| a + b), None);
+        }
+    }
+
+    #[test]
+    fn map_reduce_with_works() {
+        let items = (0..100).collect::<Vec<usize>>();
+        let inits = AtomicUsize::new(0);
+
+        let total = Pool::new(4).with_chunk_size(3).map_reduce_with(
+            &items,
+            || inits.fetch_add(1, Ordering::Relaxed),
+            |_, item| *item,
+            |a, b| a + b,
+        );
+
+        assert_eq!(total, Some(4950));
+        assert!((1..=4).contains(&inits.load(Ordering::Relaxed)));
+    }
+
+    #[test]
+    fn map_works() {
+        let items = (0..1000).collect::<Vec<usize>>();
+
+        for threads in [1, 3, 16] {
+            assert_eq!(
+                Pool::new(threads).map(&items, |n| n * n),
+                items.iter().map(|n| n * n).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn zero_threads_runs_sequentially() {
+        assert_eq!(Pool::new(0).threads(), 1);
+        assert_eq!(
+            Pool::new(0).map_reduce(&[1, 2, 3], |n| *n, |a, b| a + b),
+            Some(6)
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{
+    answer::Answer,
+    solution::{Part, PartRun},
+};
+
+/// How solver results are printed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum)]
+pub enum Format {
+    /// Human-readable lines.
+    #[default]
+    Text,
+    /// One JSON object per line, for scripts and dashboards.
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    NotFound,
+    Unsolved,
+    Skipped,
+    DidNotFinish,
+    Error,
+}
+
+/// The outcome of solving one part of a day against one input, or of failing to get that far.
+///
+/// `part`, `answer` and `duration_ns` are `null` when they don't apply, e.g. `part` for an input
+/// that could not be read or parsed.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: Option<Part>,
+    pub input: String,
+    pub status: Status,
+    pub answer: Option<Answer>,
+    pub duration_ns: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn solved(day: u8, input: &str, part_run: &PartRun) -> Self {
+        let (status, error) = match &part_run.answer {
+            Answer::Number(_) | Answer::Text(_) => (Status::Ok, None),
+            Answer::NotFound => (Status::NotFound, None),
+            Answer::Unsolved => (Status::Unsolved, None),
+            Answer::DidNotFinish(reason) => (Status::DidNotFinish, Some(reason.clone())),
+        };
+
+        Self {
+            day,
+            part: Some(part_run.part),
+            input: input.to_string(),
+            status,
+            answer: (status == Status::Ok).then(|| part_run.answer.clone()),
+            duration_ns: Some(nanoseconds(part_run.duration)),
+            error,
+        }
+    }
+
+    pub fn skipped(day: u8, input: &str, part: Part) -> Self {
+        Self {
+            day,
+            part: Some(part),
+            input: input.to_string(),
+            status: Status::Skipped,
+            answer: None,
+            duration_ns: None,
+            error: None,
+        }
+    }
+
+    pub fn error(day: u8, input: &str, error: impl ToString) -> Self {
+        Self {
+            day,
+            part: None,
+            input: input.to_string(),
+            status: Status::Error,
+            answer: None,
+            duration_ns: None,
+            error: Some(error.to_string()),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("records should always serialize")
+    }
+}
+
+fn nanoseconds(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn to_json_works() {
+        let part_run = PartRun {
+            part: Part::One,
+            answer: Answer::Text(String::from("4,6,3")),
+            duration: Duration::from_micros(12),
+        };
+
+        assert_eq!(
+            Record::solved(17, "input.txt", &part_run).to_json(),
+            r#"{"day":17,"part":1,"input":"input.txt","status":"ok","answer":"4,6,3","duration_ns":12000,"error":null}"#
+        );
+
+        let part_run = PartRun {
+            part: Part::Two,
+            answer: Answer::Number(31),
+            duration: Duration::from_nanos(5),
+        };
+
+        assert_eq!(
+            Record::solved(1, "input.txt", &part_run).to_json(),
+            r#"{"day":1,"part":2,"input":"input.txt","status":"ok","answer":31,"duration_ns":5,"error":null}"#
+        );
+
+        let part_run = PartRun {
+            part: Part::Two,
+            answer: Answer::NotFound,
+            duration: Duration::from_nanos(5),
+        };
+
+        assert_eq!(
+            Record::solved(14, "input.txt", &part_run).to_json(),
+            r#"{"day":14,"part":2,"input":"input.txt","status":"not_found","answer":null,"duration_ns":5,"error":null}"#
+        );
+
+        let part_run = PartRun {
+            part: Part::One,
+            answer: Answer::DidNotFinish(String::from("timed out after 1.00s")),
+            duration: Duration::from_secs(1),
+        };
+
+        assert_eq!(
+            Record::solved(17, "input.txt", &part_run).to_json(),
+            r#"{"day":17,"part":1,"input":"input.txt","status":"did_not_finish","answer":null,"duration_ns":1000000000,"error":"timed out after 1.00s"}"#
+        );
+
+        assert_eq!(
+            Record::error(6, "-", "line 1, column 2: oops").to_json(),
+            r#"{"day":6,"part":null,"input":"-","status":"error","answer":null,"duration_ns":null,"error":"line 1, column 2: oops"}"#
+        );
+    }
+}
@@ -0,0 +1,345 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io::BufRead,
+    time::{Duration, Instant},
+};
+
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+use tracing::{debug, info, info_span};
+
+use crate::{
+    answer::Answer,
+    bench::{bench, Bench},
+    check::Violation,
+    input::StreamError,
+};
+
+/// A day's puzzle, split into parsing the input and solving each part from the parsed input.
+pub trait Solution {
+    const DAY: u8;
+
+    /// Parts that wait for input from the user, which are skipped when benchmarking.
+    const INTERACTIVE_PARTS: &'static [Part] = &[];
+
+    type Input;
+    type ParseError: Error + Send + Sync + 'static;
+    type PartOne: Into<Answer>;
+    type PartTwo: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError>;
+
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+
+    /// Lists every way `input` breaks the assumptions the solution makes, without solving it.
+    /// Days that don't list them all report the parser's first error.
+    fn check(input: &str) -> Vec<Violation> {
+        match Self::parse(input) {
+            Ok(_) => Vec::new(),
+            Err(error) => vec![Violation::anywhere(error)],
+        }
+    }
+}
+
+/// A [`Solution`] that can also solve both parts in one pass over a reader, holding a record at a
+/// time rather than the whole input, so inputs far larger than memory can be solved.
+pub trait StreamingSolution: Solution {
+    /// What a record that does not parse fails with.
+    type RecordError: Error + Send + Sync + 'static;
+
+    fn solve_reader(reader: impl BufRead) -> StreamResult<Self>;
+}
+
+/// Both parts' answers from [`StreamingSolution::solve_reader`], or why the input could not be
+/// solved.
+pub type StreamResult<S> = Result<
+    (<S as Solution>::PartOne, <S as Solution>::PartTwo),
+    StreamError<<S as StreamingSolution>::RecordError>,
+>;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.number())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum PartSelection {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+impl PartSelection {
+    pub fn parts(&self) -> Vec<Part> {
+        match self {
+            PartSelection::One => vec![Part::One],
+            PartSelection::Two => vec![Part::Two],
+            PartSelection::Both => vec![Part::One, Part::Two],
+        }
+    }
+
+    /// The selection without `parts`, or `None` if nothing would be left.
+    pub fn excluding(&self, parts: &[Part]) -> Option<Self> {
+        let remaining = self
+            .parts()
+            .into_iter()
+            .filter(|part| !parts.contains(part))
+            .collect::<Vec<_>>();
+
+        match remaining.as_slice() {
+            [Part::One, Part::Two] => Some(PartSelection::Both),
+            [Part::One] => Some(PartSelection::One),
+            [Part::Two] => Some(PartSelection::Two),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Run {
+    pub parse_duration: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+#[derive(Clone, Debug)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Answer,
+    pub duration: Duration,
+}
+
+impl PartRun {
+    /// Whether the part gave up before reaching an answer.
+    pub fn did_not_finish(&self) -> bool {
+        matches!(self.answer, Answer::DidNotFinish(_))
+    }
+}
+
+/// How far [`solve_reporting`] has got, reported as each stage finishes.
+#[derive(Debug)]
+pub enum Progress {
+    Parsed(Duration),
+    Solved(PartRun),
+}
+
+/// Where [`solve_reporting`] sends its [`Progress`].
+pub type Report<'a> = &'a mut dyn FnMut(Progress);
+
+pub fn solve<S: Solution>(input: &str, selection: PartSelection) -> Result<Run, S::ParseError> {
+    solve_reporting::<S>(input, selection, &mut |_| {})
+}
+
+/// Like [`solve`], also passing each stage to `report` as soon as it finishes, so a caller that
+/// stops waiting still has the answers found so far.
+pub fn solve_reporting<S: Solution>(
+    input: &str,
+    selection: PartSelection,
+    report: Report,
+) -> Result<Run, S::ParseError> {
+    let _day = info_span!("day", day = S::DAY).entered();
+
+    let start = Instant::now();
+    let parsed_input = S::parse(input)?;
+    let parse_duration = start.elapsed();
+    debug!(duration = ?parse_duration, "parsed input");
+    report(Progress::Parsed(parse_duration));
+
+    let parts = selection
+        .parts()
+        .into_iter()
+        .map(|part| {
+            let _part = info_span!("part", part = part.number()).entered();
+
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part_one(&parsed_input).into(),
+                Part::Two => S::part_two(&parsed_input).into(),
+            };
+            let duration = start.elapsed();
+            info!(%answer, ?duration, "solved");
+
+            let part_run = PartRun {
+                part,
+                answer,
+                duration,
+            };
+            report(Progress::Solved(part_run.clone()));
+
+            part_run
+        })
+        .collect();
+
+    Ok(Run {
+        parse_duration,
+        parts,
+    })
+}
+
+/// Solves the selected parts in one pass over `reader`. Parsing and solving are interleaved, so the
+/// whole pass counts as parsing, and the parts take no time of their own.
+pub fn solve_stream<S: StreamingSolution>(
+    reader: impl BufRead,
+    selection: PartSelection,
+) -> Result<Run, StreamError<S::RecordError>> {
+    let _day = info_span!("day", day = S::DAY).entered();
+
+    let start = Instant::now();
+    let (part_one, part_two) = S::solve_reader(reader)?;
+    let parse_duration = start.elapsed();
+    info!(duration = ?parse_duration, "solved both parts in one pass");
+
+    let answers: [Answer; 2] = [part_one.into(), part_two.into()];
+
+    let parts = selection
+        .parts()
+        .into_iter()
+        .map(|part| PartRun {
+            part,
+            answer: answers[usize::from(part.number()) - 1].clone(),
+            duration: Duration::ZERO,
+        })
+        .collect();
+
+    Ok(Run {
+        parse_duration,
+        parts,
+    })
+}
+
+/// A parse error from any day's [`Solution`].
+pub type AnyParseError = Box<dyn Error + Send + Sync>;
+
+/// A type-erased handle to a day's [`Solution`], so that days can be listed together.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub interactive_parts: &'static [Part],
+    pub solve: fn(&str, PartSelection) -> Result<Run, AnyParseError>,
+    pub solve_reporting: fn(&str, PartSelection, Report) -> Result<Run, AnyParseError>,
+    pub bench: fn(&str, &[Part], usize) -> Result<Bench, AnyParseError>,
+    pub check: fn(&str) -> Vec<Violation>,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            interactive_parts: S::INTERACTIVE_PARTS,
+            solve: |input, selection| Ok(solve::<S>(input, selection)?),
+            solve_reporting: |input, selection, report| {
+                Ok(solve_reporting::<S>(input, selection, report)?)
+            },
+            bench: |input, parts, iterations| Ok(bench::<S>(input, parts, iterations)?),
+            check: S::check,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::num::ParseIntError;
+
+    use super::*;
+
+    struct Sums;
+
+    impl Solution for Sums {
+        const DAY: u8 = 0;
+
+        type Input = Vec<usize>;
+        type ParseError = ParseIntError;
+        type PartOne = usize;
+        type PartTwo = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+            input.split(',').map(|n| n.parse()).collect()
+        }
+
+        fn part_one(input: &Self::Input) -> Self::PartOne {
+            input.iter().sum()
+        }
+
+        fn part_two(input: &Self::Input) -> Self::PartTwo {
+            input.iter().product()
+        }
+    }
+
+    #[test]
+    fn solve_works() {
+        let run = solve::<Sums>("2,3,4", PartSelection::Both).unwrap();
+
+        assert_eq!(
+            run.parts
+                .iter()
+                .map(|part_run| (part_run.part, part_run.answer.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Part::One, Answer::Number(9)),
+                (Part::Two, Answer::Number(24))
+            ]
+        );
+
+        let run = solve::<Sums>("2,3,4", PartSelection::Two).unwrap();
+
+        assert_eq!(
+            run.parts
+                .iter()
+                .map(|part_run| part_run.part)
+                .collect::<Vec<_>>(),
+            vec![Part::Two]
+        );
+    }
+
+    #[test]
+    fn part_selection_excluding_works() {
+        assert_eq!(
+            PartSelection::Both.excluding(&[]),
+            Some(PartSelection::Both)
+        );
+        assert_eq!(
+            PartSelection::Both.excluding(&[Part::Two]),
+            Some(PartSelection::One)
+        );
+        assert_eq!(PartSelection::Two.excluding(&[Part::Two]), None);
+    }
+
+    #[test]
+    fn solve_reports_parse_errors() {
+        assert!(solve::<Sums>("2,x,4", PartSelection::Both).is_err());
+
+        let day = Day::of::<Sums>();
+        assert_eq!(
+            (day.solve)("2,x,4", PartSelection::Both)
+                .unwrap_err()
+                .to_string(),
+            "invalid digit found in string"
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{self, Display},
+    io::BufRead,
+    iter::zip,
+};
+
+use common::{
+    check::check_lines,
+    input::{self, StreamError},
+    Located, Solution, StreamingSolution, Violation,
+};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = (Vec<usize>, Vec<usize>);
+    type ParseError = Located<ParseInputError>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        parse_input(input)
+    }
+
+    fn check(input: &str) -> Vec<Violation> {
+        check_lines(input, |line| parse_input_line(input, line))
+    }
+
+    fn part_one((left, right): &Self::Input) -> Self::PartOne {
+        total_distance_between_lists(left, right)
+    }
+
+    fn part_two((left, right): &Self::Input) -> Self::PartTwo {
+        similarity_score(left, right)
+    }
+}
+
+impl StreamingSolution for Day1 {
+    type RecordError = ParseInputError;
+
+    fn solve_reader(
+        reader: impl BufRead,
+    ) -> Result<(usize, usize), StreamError<Self::RecordError>> {
+        let counts = LocationIdCounts::from_reader(reader)?;
+
+        Ok((counts.total_distance(), counts.similarity_score()))
+    }
+}
+
+pub fn total_distance_between_lists(left: &[usize], right: &[usize]) -> usize {
+    let mut left = left.to_vec();
+    let mut right = right.to_vec();
+
+    left.sort();
+    right.sort();
+
+    zip(left, right)
+        .map(|(first, second)| first.abs_diff(second))
+        .sum()
+}
+
+pub fn similarity_score(left: &[usize], right: &[usize]) -> usize {
+    left.iter()
+        .map(|left_number| {
+            right
+                .iter()
+                .filter(|&right_number| right_number == left_number)
+                .count()
+                * left_number
+        })
+        .sum()
+}
+
+/// How many times each location ID appears in each list. Both parts only depend on these counts,
+/// so the lists can be read in memory bounded by the number of distinct IDs, however long they
+/// are. Walking the counts in ID order stands in for sorting the lists.
+#[derive(Debug, Default, PartialEq)]
+pub struct LocationIdCounts {
+    left: BTreeMap<usize, usize>,
+    right: BTreeMap<usize, usize>,
+}
+
+impl LocationIdCounts {
+    pub fn from_reader(reader: impl BufRead) -> Result<Self, StreamError<ParseInputError>> {
+        let mut counts = Self::default();
+
+        input::for_each_line(reader, |line| {
+            let (left, right) = parse_input_line(line, line)?;
+
+            *counts.left.entry(left).or_default() += 1;
+            *counts.right.entry(right).or_default() += 1;
+
+            Ok(())
+        })?;
+
+        Ok(counts)
+    }
+
+    /// The same as [`total_distance_between_lists`], pairing up the smallest remaining IDs of each
+    /// list as many times as both still have them.
+    pub fn total_distance(&self) -> usize {
+        let mut left = self.left.iter().map(|(&id, &count)| (id, count));
+        let mut right = self.right.iter().map(|(&id, &count)| (id, count));
+
+        let mut next_left = left.next();
+        let mut next_right = right.next();
+
+        let mut total_distance = 0;
+
+        while let (Some((left_id, left_count)), Some((right_id, right_count))) =
+            (next_left, next_right)
+        {
+            let pairs = left_count.min(right_count);
+            total_distance += left_id.abs_diff(right_id) * pairs;
+
+            next_left = match left_count - pairs {
+                0 => left.next(),
+                remaining => Some((left_id, remaining)),
+            };
+            next_right = match right_count - pairs {
+                0 => right.next(),
+                remaining => Some((right_id, remaining)),
+            };
+        }
+
+        total_distance
+    }
+
+    /// The same as [`similarity_score`].
+    pub fn similarity_score(&self) -> usize {
+        self.left
+            .iter()
+            .map(|(id, count)| id * count * self.right.get(id).copied().unwrap_or_default())
+            .sum()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseInputError {
+    MissingSeparator(String),
+    InvalidLocationId(String),
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseInputError::MissingSeparator(line) => write!(
+                f,
+                "expected two location IDs separated by three spaces, found {:?}",
+                line
+            ),
+            ParseInputError::InvalidLocationId(text) => {
+                write!(f, "invalid location ID {:?}", text)
+            }
+        }
+    }
+}
+
+impl Error for ParseInputError {}
+
+pub fn parse_input(input: &str) -> Result<(Vec<usize>, Vec<usize>), Located<ParseInputError>> {
+    input
+        .lines()
+        .map(|line| parse_input_line(input, line))
+        .collect()
+}
+
+fn parse_input_line(
+    input: &str,
+    input_line: &str,
+) -> Result<(usize, usize), Located<ParseInputError>> {
+    let (first, second) = input_line.split_once("   ").ok_or_else(|| {
+        Located::at(
+            input,
+            input_line,
+            ParseInputError::MissingSeparator(input_line.to_string()),
+        )
+    })?;
+
+    let parse_location_id = |text: &str| {
+        text.trim().parse::<usize>().map_err(|_| {
+            Located::at(
+                input,
+                text,
+                ParseInputError::InvalidLocationId(text.to_string()),
+            )
+        })
+    };
+
+    Ok((parse_location_id(first)?, parse_location_id(second)?))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use common::Location;
+
+    const INPUT: &str = include_str!("aoc-input/example-input.txt");
+
+    #[test]
+    fn total_distance_between_lists_works() {
+        let (left, right) = parse_input(INPUT).unwrap();
+
+        assert_eq!(total_distance_between_lists(&left, &right), 11)
+    }
+
+    #[test]
+    fn similarity_score_works() {
+        let (left, right) = parse_input(INPUT).unwrap();
+
+        assert_eq!(similarity_score(&left, &right), 31)
+    }
+
+    #[test]
+    fn parse_input_rejects_malformed_input() {
+        assert_eq!(
+            parse_input("3   4\n4 3\n").unwrap_err().to_string(),
+            "line 2, column 1: expected two location IDs separated by three spaces, found \"4 3\""
+        );
+        assert_eq!(
+            parse_input("3   4\n4   x3\n").unwrap_err().to_string(),
+            "line 2, column 5: invalid location ID \"x3\""
+        );
+    }
+
+    #[test]
+    fn solve_reader_works() {
+        assert_eq!(Day1::solve_reader(INPUT.as_bytes()).unwrap(), (11, 31));
+
+        // Each list holds every ID 1000 times as often, so every pair repeats 1000 times and every
+        // left ID matches 1000 times as many right IDs, 1000 times over.
+        let repeated = INPUT.repeat(1000);
+        assert_eq!(
+            Day1::solve_reader(repeated.as_bytes()).unwrap(),
+            (11 * 1000, 31 * 1000 * 1000)
+        );
+
+        assert_eq!(
+            Day1::solve_reader("3   4\n4   x3\n".as_bytes())
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 5: invalid location ID \"x3\""
+        );
+    }
+
+    #[test]
+    fn check_works() {
+        assert!(Day1::check(INPUT).is_empty());
+        assert_eq!(
+            Day1::check("3   4\n4 3\n5   x\n"),
+            vec![
+                Violation::at(
+                    Location::new(2, 1),
+                    "expected two location IDs separated by three spaces, found \"4 3\""
+                ),
+                Violation::at(Location::new(3, 5), "invalid location ID \"x\""),
+            ]
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::Debug,
+    str::FromStr,
+};
+
+use common::{Solution, Violation};
+use geometry::Position;
+use grid::{check_grid, Grid, ParseGridError};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = TopographicalMap;
+    type ParseError = ParseGridError;
+    type PartOne = u16;
+    type PartTwo = u16;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        TopographicalMap::from_str(input)
+    }
+
+    fn check(input: &str) -> Vec<Violation> {
+        check_grid(input, |character| character.is_ascii_digit())
+            .into_iter()
+            .map(Violation::from)
+            .collect()
+    }
+
+    fn part_one(topographical_map: &Self::Input) -> Self::PartOne {
+        sum_of_trailhead_scores(topographical_map)
+    }
+
+    fn part_two(topographical_map: &Self::Input) -> Self::PartTwo {
+        sum_of_trailhead_ratings(topographical_map)
+    }
+}
+
+pub fn sum_of_trailhead_scores(topographical_map: &TopographicalMap) -> u16 {
+    topographical_map
+        .trailhead_positions()
+        .iter()
+        .map(|trailhead_position| calculate_trailhead_score(trailhead_position, topographical_map))
+        .sum()
+}
+
+pub fn sum_of_trailhead_ratings(topographical_map: &TopographicalMap) -> u16 {
+    topographical_map
+        .trailhead_positions()
+        .iter()
+        .map(|trailhead_position| calculate_trailhead_rating(trailhead_position, topographical_map))
+        .sum()
+}
+
+pub fn calculate_trailhead_score(
+    trailhead_position: &Position,
+    topographical_map: &TopographicalMap,
+) -> u16 {
+    let mut stack = VecDeque::from([(*trailhead_position, 0)]);
+    let mut nine_height_positions_reachable = HashSet::new();
+
+    while !stack.is_empty() {
+        let (position, height) = stack.pop_back().unwrap();
+
+        for translated_position in topographical_map.heights.neighbours_4(position) {
+            let translated_position_height = topographical_map.heights[translated_position];
+
+            if translated_position_height == height + 1 {
+                if translated_position_height == 9 {
+                    nine_height_positions_reachable.insert(translated_position);
+                    continue;
+                }
+
+                stack.push_back((translated_position, translated_position_height));
+            }
+        }
+    }
+
+    nine_height_positions_reachable.len().try_into().unwrap()
+}
+
+pub fn calculate_trailhead_rating(
+    trailhead_position: &Position,
+    topographical_map: &TopographicalMap,
+) -> u16 {
+    let mut stack = VecDeque::from([(*trailhead_position, 0)]);
+    let mut rating = 0u16;
+
+    while !stack.is_empty() {
+        let (position, height) = stack.pop_back().unwrap();
+
+        for translated_position in topographical_map.heights.neighbours_4(position) {
+            let translated_position_height = topographical_map.heights[translated_position];
+
+            if translated_position_height == height + 1 {
+                if translated_position_height == 9 {
+                    rating += 1;
+                    continue;
+                }
+
+                stack.push_back((translated_position, translated_position_height));
+            }
+        }
+    }
+
+    rating
+}
+
+#[derive(Debug)]
+pub struct TopographicalMap {
+    heights: Grid<u8>,
+    trailhead_positions: HashSet<Position>,
+}
+
+impl FromStr for TopographicalMap {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let heights = Grid::parse_with(s, |character| {
+            character.to_digit(10).map(|height| height as u8)
+        })?;
+
+        let trailhead_positions = heights.positions_of(&0).collect();
+
+        Ok(Self {
+            heights,
+            trailhead_positions,
+        })
+    }
+}
+
+impl TopographicalMap {
+    pub fn trailhead_positions(&self) -> &HashSet<Position> {
+        &self.trailhead_positions
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use common::Location;
+
+    const EXAMPLE_INPUT: &str = include_str!("aoc-input/example-input.txt");
+
+    #[test]
+    fn sum_of_trailhead_scores_works() {
+        assert_eq!(
+            sum_of_trailhead_scores(&TopographicalMap::from_str(EXAMPLE_INPUT).unwrap()),
+            36
+        );
+    }
+
+    #[test]
+    fn sum_of_trailhead_ratings_works() {
+        assert_eq!(
+            sum_of_trailhead_ratings(&TopographicalMap::from_str(EXAMPLE_INPUT).unwrap()),
+            81
+        );
+    }
+
+    #[test]
+    fn calculate_trailhead_score_works() {
+        let topographical_map = TopographicalMap::from_str(EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(
+            calculate_trailhead_score(&Position::new(2, 0), &topographical_map),
+            5
+        );
+        assert_eq!(
+            calculate_trailhead_score(&Position::new(4, 0), &topographical_map),
+            6
+        );
+        assert_eq!(
+            calculate_trailhead_score(&Position::new(4, 2), &topographical_map),
+            5
+        );
+        assert_eq!(
+            calculate_trailhead_score(&Position::new(6, 4), &topographical_map),
+            3
+        );
+        assert_eq!(
+            calculate_trailhead_score(&Position::new(2, 5), &topographical_map),
+            1
+        );
+        assert_eq!(
+            calculate_trailhead_score(&Position::new(5, 5), &topographical_map),
+            3
+        );
+        assert_eq!(
+            calculate_trailhead_score(&Position::new(0, 6), &topographical_map),
+            5
+        );
+        assert_eq!(
+            calculate_trailhead_score(&Position::new(6, 6), &topographical_map),
+            3
+        );
+        assert_eq!(
+            calculate_trailhead_score(&Position::new(1, 7), &topographical_map),
+            5
+        );
+    }
+
+    #[test]
+    fn calculate_trailhead_rating_works() {
+        let topographical_map = TopographicalMap::from_str(EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(
+            calculate_trailhead_rating(&Position::new(2, 0), &topographical_map),
+            20
+        );
+        assert_eq!(
+            calculate_trailhead_rating(&Position::new(4, 0), &topographical_map),
+            24
+        );
+        assert_eq!(
+            calculate_trailhead_rating(&Position::new(4, 2), &topographical_map),
+            10
+        );
+        assert_eq!(
+            calculate_trailhead_rating(&Position::new(6, 4), &topographical_map),
+            4
+        );
+        assert_eq!(
+            calculate_trailhead_rating(&Position::new(2, 5), &topographical_map),
+            1
+        );
+        assert_eq!(
+            calculate_trailhead_rating(&Position::new(5, 5), &topographical_map),
+            4
+        );
+        assert_eq!(
+            calculate_trailhead_rating(&Position::new(0, 6), &topographical_map),
+            5
+        );
+        assert_eq!(
+            calculate_trailhead_rating(&Position::new(6, 6), &topographical_map),
+            8
+        );
+        assert_eq!(
+            calculate_trailhead_rating(&Position::new(1, 7), &topographical_map),
+            5
+        );
+    }
+
+    #[test]
+    fn check_works() {
+        assert!(Day10::check(EXAMPLE_INPUT).is_empty());
+        assert_eq!(
+            Day10::check("0123\n1.34\n234\n..56"),
+            vec![
+                Violation::at(Location::new(2, 2), "unexpected character '.'"),
+                Violation::at(Location::new(3, 1), "line is 3 characters long, expected 4"),
+                Violation::at(Location::new(4, 1), "unexpected character '.'"),
+                Violation::at(Location::new(4, 2), "unexpected character '.'"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Display},
+};
+
+use common::{Located, Solution, Violation};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = HashMap<usize, usize>;
+    type ParseError = Located<ParseInputError>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        parse_input(input)
+    }
+
+    fn check(input: &str) -> Vec<Violation> {
+        input
+            .split_whitespace()
+            .filter(|stone_string| stone_string.parse::<usize>().is_err())
+            .map(|stone_string| {
+                Violation::from(Located::at(
+                    input,
+                    stone_string,
+                    ParseInputError(stone_string.to_string()),
+                ))
+            })
+            .collect()
+    }
+
+    fn part_one(stones: &Self::Input) -> Self::PartOne {
+        stone_count_after_blinks(stones, 25)
+    }
+
+    fn part_two(stones: &Self::Input) -> Self::PartTwo {
+        stone_count_after_blinks(stones, 75)
+    }
+}
+
+pub fn stone_count_after_blinks(stones: &HashMap<usize, usize>, blinks: usize) -> usize {
+    let mut stones = stones.clone();
+
+    for _ in 0..blinks {
+        for (&stone, &count) in stones.clone().iter().filter(|(_, &count)| count > 0) {
+            *stones.get_mut(&stone).unwrap() -= count;
+
+            let updated_stones = apply_rules(stone);
+
+            for updated_stone in updated_stones {
+                *stones.entry(updated_stone).or_default() += count;
+            }
+        }
+    }
+
+    stones.values().sum()
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParseInputError(String);
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid stone {:?}", self.0)
+    }
+}
+
+impl Error for ParseInputError {}
+
+pub fn parse_input(input: &str) -> Result<HashMap<usize, usize>, Located<ParseInputError>> {
+    let mut result = HashMap::new();
+    let stones = input.split_whitespace().map(|stone_string| {
+        stone_string.parse::<usize>().map_err(|_| {
+            Located::at(
+                input,
+                stone_string,
+                ParseInputError(stone_string.to_string()),
+            )
+        })
+    });
+    for stone in stones {
+        *result.entry(stone?).or_default() += 1;
+    }
+    Ok(result)
+}
+
+pub fn apply_rules(stone: usize) -> Vec<usize> {
+    if stone == 0 {
+        return vec![1];
+    }
+
+    if let Some((part_1, part_2)) = split_even_digit_count_number(&stone) {
+        return vec![part_1, part_2];
+    }
+
+    vec![stone * 2024]
+}
+
+fn split_even_digit_count_number(number: &usize) -> Option<(usize, usize)> {
+    let number_of_digits = number.ilog10() + 1;
+    if !number_of_digits.is_multiple_of(2) {
+        None
+    } else {
+        let split_value = 10usize.pow(number_of_digits / 2);
+        let part_1 = number / (split_value);
+        let part_2 = number % (split_value);
+        Some((part_1, part_2))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use common::Location;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT_1: &str = include_str!("aoc-input/example-input-1.txt");
+
+    const EXAMPLE_INPUT_2: &str = include_str!("aoc-input/example-input-2.txt");
+
+    #[test]
+    fn stone_count_after_blinks_works() {
+        assert_eq!(
+            stone_count_after_blinks(&parse_input(EXAMPLE_INPUT_1).unwrap(), 1),
+            7
+        );
+        assert_eq!(
+            stone_count_after_blinks(&parse_input(EXAMPLE_INPUT_2).unwrap(), 6),
+            22
+        );
+        assert_eq!(
+            stone_count_after_blinks(&parse_input(EXAMPLE_INPUT_2).unwrap(), 25),
+            55312
+        );
+    }
+
+    #[test]
+    fn apply_rules_works() {
+        assert_eq!(apply_rules(0), vec![1]);
+        assert_eq!(apply_rules(253010), vec![253, 10]);
+        assert_eq!(apply_rules(1036288), vec![2097446912]);
+    }
+
+    /// Blinks by literally rewriting the row of stones, splitting their digits as text.
+    fn naive_stone_count_after_blinks(stones: &[usize], blinks: usize) -> usize {
+        let mut stones = stones.to_vec();
+
+        for _ in 0..blinks {
+            stones = stones
+                .into_iter()
+                .flat_map(|stone| {
+                    let digits = stone.to_string();
+
+                    if stone == 0 {
+                        vec![1]
+                    } else if digits.len() % 2 == 0 {
+                        let (left, right) = digits.split_at(digits.len() / 2);
+                        vec![left.parse().unwrap(), right.parse().unwrap()]
+                    } else {
+                        vec![stone * 2024]
+                    }
+                })
+                .collect();
+        }
+
+        stones.len()
+    }
+
+    proptest! {
+        #[test]
+        fn stone_count_after_blinks_matches_list_expansion(
+            stones in proptest::collection::vec(0..10_000usize, 1..4),
+            blinks in 0..20usize,
+        ) {
+            let input = stones.iter().map(usize::to_string).collect::<Vec<_>>().join(" ");
+
+            prop_assert_eq!(
+                stone_count_after_blinks(&parse_input(&input).unwrap(), blinks),
+                naive_stone_count_after_blinks(&stones, blinks),
+                "input {:?} after {} blinks",
+                input,
+                blinks
+            );
+        }
+
+        #[test]
+        fn apply_rules_never_loses_digits(
+            stone in prop_oneof![0..100_000usize, 0..usize::MAX / 2024]
+        ) {
+            match apply_rules(stone).as_slice() {
+                [1] if stone == 0 => {}
+                [part_1, part_2] => {
+                    let digits = stone.ilog10() + 1;
+                    prop_assert!(digits.is_multiple_of(2));
+                    prop_assert_eq!(part_1.ilog10() + 1, digits / 2);
+                    prop_assert_eq!(part_1 * 10usize.pow(digits / 2) + part_2, stone);
+                }
+                [multiplied] => {
+                    prop_assert!(!(stone.ilog10() + 1).is_multiple_of(2));
+                    prop_assert_eq!(*multiplied, stone * 2024);
+                }
+                stones => prop_assert!(false, "{} became {:?}", stone, stones),
+            }
+        }
+    }
+
+    #[test]
+    fn check_works() {
+        assert!(Day11::check(EXAMPLE_INPUT_1).is_empty());
+        assert_eq!(
+            Day11::check("125 -17 x\n99999999999999999999"),
+            vec![
+                Violation::at(Location::new(1, 5), "invalid stone \"-17\""),
+                Violation::at(Location::new(1, 9), "invalid stone \"x\""),
+                Violation::at(
+                    Location::new(2, 1),
+                    "invalid stone \"99999999999999999999\""
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,469 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use common::{Solution, Violation};
+use geometry::{Direction, Position};
+use grid::{check_grid, Grid, ParseGridError};
+use visualizer::{Cell, Colour, Frames};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = GardenPlotsGrid;
+    type ParseError = ParseGridError;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        GardenPlotsGrid::from_str(input)
+    }
+
+    fn check(input: &str) -> Vec<Violation> {
+        check_grid(input, |character| character.is_ascii_uppercase())
+            .into_iter()
+            .map(Violation::from)
+            .collect()
+    }
+
+    fn part_one(grid: &Self::Input) -> Self::PartOne {
+        fencing_total_price(grid)
+    }
+
+    fn part_two(grid: &Self::Input) -> Self::PartTwo {
+        fencing_total_price_with_bulk_discount(grid)
+    }
+}
+
+pub fn fencing_total_price(grid: &GardenPlotsGrid) -> usize {
+    let regions = calculate_regions(grid);
+
+    regions
+        .iter()
+        .map(|region| {
+            let mut perimeter = 0;
+
+            for position in region {
+                perimeter += Direction::orthogonal()
+                    .iter()
+                    .filter(|direction| {
+                        let Some(adjacent_position) = position.translate(**direction) else {
+                            return true;
+                        };
+                        !region.contains(&adjacent_position)
+                    })
+                    .count();
+            }
+
+            perimeter * region.len()
+        })
+        .sum()
+}
+
+pub fn fencing_total_price_with_bulk_discount(grid: &GardenPlotsGrid) -> usize {
+    let regions = calculate_regions(grid);
+
+    regions
+        .iter()
+        .map(|region| {
+            let mut grouped_edges: HashMap<(Direction, usize), HashSet<usize>> = HashMap::new();
+
+            for position in region {
+                for direction in Direction::orthogonal() {
+                    let main_axis = match direction {
+                        Direction::Up | Direction::Down => position.y,
+                        _ => position.x,
+                    };
+                    let secondary_axis = match direction {
+                        Direction::Up | Direction::Down => position.x,
+                        _ => position.y,
+                    };
+                    let Some(adjacent_position) = position.translate(direction) else {
+                        grouped_edges
+                            .entry((direction, main_axis))
+                            .or_default()
+                            .insert(secondary_axis);
+                        continue;
+                    };
+                    if region.contains(&adjacent_position) {
+                        continue;
+                    }
+                    grouped_edges
+                        .entry((direction, main_axis))
+                        .or_default()
+                        .insert(secondary_axis);
+                }
+            }
+
+            grouped_edges
+                .values()
+                .map(|positions| {
+                    let mut positions = positions.iter().cloned().collect::<Vec<_>>();
+
+                    positions.sort();
+
+                    positions
+                        .windows(2)
+                        .filter(|&x| {
+                            let a = x.first().unwrap();
+                            let b = x.last().unwrap();
+                            *b != *a + 1
+                        })
+                        .count()
+                        + 1
+                })
+                .sum::<usize>()
+                * region.len()
+        })
+        .sum()
+}
+
+pub fn calculate_regions(grid: &GardenPlotsGrid) -> Vec<HashSet<Position>> {
+    let mut plots_by_plant: HashMap<char, HashSet<Position>> = HashMap::new();
+
+    for (position, plant) in grid.plants.iter() {
+        let plant_regions = plots_by_plant.entry(*plant).or_default();
+        plant_regions.insert(position);
+    }
+
+    let mut regions: Vec<HashSet<Position>> = Vec::new();
+
+    for (_, positions) in plots_by_plant {
+        let mut plant_regions: Vec<HashSet<Position>> = Vec::new();
+
+        for position in positions {
+            let plant_regions_clone = plant_regions.clone();
+
+            let adjacent_regions = get_adjacent_regions(&plant_regions_clone, &position);
+
+            if adjacent_regions.is_empty() {
+                plant_regions.push(HashSet::from([position]));
+            } else if adjacent_regions.len() == 1 {
+                plant_regions
+                    .iter_mut()
+                    .find(|pr| pr == adjacent_regions.first().unwrap())
+                    .unwrap()
+                    .insert(position);
+            } else {
+                let mut merged_region = HashSet::from([position]);
+                for &adjacent_region in adjacent_regions.as_slice() {
+                    for position in adjacent_region {
+                        merged_region.insert(*position);
+                    }
+                }
+                plant_regions.retain(|pr| !adjacent_regions.contains(&pr));
+                plant_regions.push(merged_region);
+            }
+        }
+
+        for plant_region in plant_regions {
+            regions.push(plant_region);
+        }
+    }
+
+    regions
+}
+
+fn get_adjacent_regions<'a>(
+    regions: &'a [HashSet<Position>],
+    position: &Position,
+) -> Vec<&'a HashSet<Position>> {
+    regions
+        .iter()
+        .filter(|region| {
+            Direction::orthogonal().iter().any(|direction| {
+                let Some(adjacent_position) = position.translate(*direction) else {
+                    return false;
+                };
+                region.contains(&adjacent_position)
+            })
+        })
+        .collect::<Vec<_>>()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RegionCell {
+    Unmapped(char),
+    Region { plant: char, id: usize },
+}
+
+impl Cell for RegionCell {
+    fn symbol(&self) -> char {
+        match self {
+            RegionCell::Unmapped(plant) | RegionCell::Region { plant, .. } => *plant,
+        }
+    }
+
+    fn colour(&self) -> Colour {
+        match self {
+            RegionCell::Unmapped(_) => Colour::Grey,
+            RegionCell::Region { id, .. } => region_colour(*id),
+        }
+    }
+}
+
+/// Spreads hues around the colour wheel by the golden angle, so neighbouring ids stand apart.
+fn region_colour(id: usize) -> Colour {
+    let hue = (id as f64 * 137.508) % 360.0;
+    let sector = hue / 60.0;
+    let rising = (255.0 * (1.0 - (sector % 2.0 - 1.0).abs())) as u8;
+
+    match sector as u8 {
+        0 => Colour::Rgb(255, rising, 0),
+        1 => Colour::Rgb(rising, 255, 0),
+        2 => Colour::Rgb(0, 255, rising),
+        3 => Colour::Rgb(0, rising, 255),
+        4 => Colour::Rgb(rising, 0, 255),
+        _ => Colour::Rgb(255, 0, rising),
+    }
+}
+
+/// The garden's regions, coloured by id and revealed one region per frame, in reading order of
+/// their first plot.
+pub struct RegionMap {
+    plants: Grid<char>,
+    regions: Vec<Vec<Position>>,
+}
+
+impl RegionMap {
+    pub fn new(grid: &GardenPlotsGrid) -> Self {
+        let mut regions = calculate_regions(grid)
+            .into_iter()
+            .map(|region| {
+                let mut region = region.into_iter().collect::<Vec<_>>();
+                region.sort_by_key(|position| (position.y, position.x));
+                region
+            })
+            .collect::<Vec<_>>();
+
+        regions.sort_by_key(|region| (region[0].y, region[0].x));
+
+        Self {
+            plants: grid.plants.clone(),
+            regions,
+        }
+    }
+}
+
+impl Frames for RegionMap {
+    type Cell = RegionCell;
+
+    fn frame_count(&self) -> usize {
+        self.regions.len()
+    }
+
+    fn frame(&mut self, index: usize) -> Grid<Self::Cell> {
+        let mut frame = self.plants.map(|&plant| RegionCell::Unmapped(plant));
+
+        for (id, region) in self.regions[..=index].iter().enumerate() {
+            for &position in region {
+                frame[position] = RegionCell::Region {
+                    plant: self.plants[position],
+                    id,
+                };
+            }
+        }
+
+        frame
+    }
+}
+
+pub struct GardenPlotsGrid {
+    plants: Grid<char>,
+}
+
+impl FromStr for GardenPlotsGrid {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let plants = Grid::from_str(s)?;
+
+        Ok(Self { plants })
+    }
+}
+
+impl GardenPlotsGrid {
+    pub fn plants(&self) -> &Grid<char> {
+        &self.plants
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::VecDeque;
+
+    use super::*;
+    use common::Location;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT_1: &str = include_str!("aoc-input/example-input-1.txt");
+
+    const EXAMPLE_INPUT_2: &str = include_str!("aoc-input/example-input-2.txt");
+
+    #[test]
+    fn fencing_total_price_works() {
+        assert_eq!(
+            fencing_total_price(&GardenPlotsGrid::from_str(EXAMPLE_INPUT_1).unwrap()),
+            140
+        );
+        assert_eq!(
+            fencing_total_price(&GardenPlotsGrid::from_str(EXAMPLE_INPUT_2).unwrap()),
+            1930
+        );
+    }
+
+    #[test]
+    fn fencing_total_price_with_bulk_discount_works() {
+        assert_eq!(
+            fencing_total_price_with_bulk_discount(
+                &GardenPlotsGrid::from_str(EXAMPLE_INPUT_1).unwrap()
+            ),
+            80
+        );
+        assert_eq!(
+            fencing_total_price_with_bulk_discount(
+                &GardenPlotsGrid::from_str(EXAMPLE_INPUT_2).unwrap()
+            ),
+            1206
+        );
+    }
+
+    #[test]
+    fn region_map_works() {
+        let mut region_map = RegionMap::new(&GardenPlotsGrid::from_str(EXAMPLE_INPUT_1).unwrap());
+
+        assert_eq!(region_map.frame_count(), 5);
+
+        let first_frame = region_map.frame(0);
+        assert_eq!(
+            first_frame[Position::new(3, 0)],
+            RegionCell::Region { plant: 'A', id: 0 }
+        );
+        assert_eq!(first_frame[Position::new(0, 1)], RegionCell::Unmapped('B'));
+
+        assert_eq!(
+            region_map.frame(4)[Position::new(3, 1)],
+            RegionCell::Region { plant: 'D', id: 3 }
+        );
+    }
+
+    /// Flood fills each region, then traces its fence one unit segment at a time: a segment is the
+    /// side of a plot facing out of the region, and a side is a run of segments facing the same
+    /// way that can be walked along. Returns the total price without and with the bulk discount.
+    fn naive_fencing_total_prices(rows: &[String]) -> (usize, usize) {
+        let plants = rows
+            .iter()
+            .map(|row| row.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let plant_at = |(x, y): (isize, isize)| {
+            let row = plants.get(usize::try_from(y).ok()?)?;
+            row.get(usize::try_from(x).ok()?).copied()
+        };
+        let steps = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+        let mut seen = HashSet::new();
+        let mut total_price = 0;
+        let mut total_discounted_price = 0;
+
+        for y in 0..plants.len() as isize {
+            for x in 0..plants[0].len() as isize {
+                if !seen.insert((x, y)) {
+                    continue;
+                }
+
+                let plant = plant_at((x, y));
+                let mut region = vec![(x, y)];
+                let mut queue = VecDeque::from([(x, y)]);
+
+                while let Some((x, y)) = queue.pop_front() {
+                    for (dx, dy) in steps {
+                        let next = (x + dx, y + dy);
+
+                        if plant_at(next) == plant && seen.insert(next) {
+                            region.push(next);
+                            queue.push_back(next);
+                        }
+                    }
+                }
+
+                let segments = region
+                    .iter()
+                    .flat_map(|&(x, y)| steps.iter().map(move |&(dx, dy)| ((x, y), (dx, dy))))
+                    .filter(|&((x, y), (dx, dy))| plant_at((x + dx, y + dy)) != plant)
+                    .collect::<HashSet<_>>();
+
+                let mut traced = HashSet::new();
+                let mut sides = 0;
+
+                for &segment in &segments {
+                    if traced.contains(&segment) {
+                        continue;
+                    }
+
+                    sides += 1;
+
+                    // Walk both ways along the fence, at right angles to the way it faces.
+                    let ((x, y), (dx, dy)) = segment;
+                    for (along_x, along_y) in [(dy, dx), (-dy, -dx)] {
+                        let mut position = (x, y);
+
+                        while segments.contains(&(position, (dx, dy))) {
+                            traced.insert((position, (dx, dy)));
+                            position = (position.0 + along_x, position.1 + along_y);
+                        }
+                    }
+                }
+
+                total_price += region.len() * segments.len();
+                total_discounted_price += region.len() * sides;
+            }
+        }
+
+        (total_price, total_discounted_price)
+    }
+
+    fn garden() -> impl Strategy<Value = Vec<String>> {
+        (1..7usize, 1..7usize).prop_flat_map(|(width, height)| {
+            proptest::collection::vec(
+                proptest::collection::vec(prop_oneof![Just('A'), Just('B'), Just('C')], width)
+                    .prop_map(|row| row.into_iter().collect::<String>()),
+                height,
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn fencing_total_prices_match_tracing_fence_segments(rows in garden()) {
+            let input = rows.join("\n");
+            let grid = GardenPlotsGrid::from_str(&input).unwrap();
+
+            let (total_price, total_discounted_price) = naive_fencing_total_prices(&rows);
+
+            prop_assert_eq!(fencing_total_price(&grid), total_price, "garden:\n{}", input);
+            prop_assert_eq!(
+                fencing_total_price_with_bulk_discount(&grid),
+                total_discounted_price,
+                "garden:\n{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn check_works() {
+        assert!(Day12::check(EXAMPLE_INPUT_1).is_empty());
+        assert!(Day12::check(EXAMPLE_INPUT_2).is_empty());
+        assert_eq!(
+            Day12::check("AAB\nAB\nCCc"),
+            vec![
+                Violation::at(Location::new(2, 1), "line is 2 characters long, expected 3"),
+                Violation::at(Location::new(3, 3), "unexpected character 'c'"),
+            ]
+        );
+        assert_eq!(Day12::check(""), vec![Violation::anywhere("grid is empty")]);
+    }
+}
@@ -0,0 +1,544 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io::BufRead,
+};
+
+use common::{
+    input::{self, StreamError},
+    Located, Location, Solution, StreamingSolution, Violation,
+};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Machine>;
+    type ParseError = Located<ParseInputError>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        parse_input(input)
+    }
+
+    fn check(input: &str) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        let lines = input.lines().collect::<Vec<_>>();
+
+        for machine_lines in lines.split(|line| line.is_empty()) {
+            let Some(last_line) = machine_lines.last() else {
+                continue;
+            };
+
+            let mut coordinates = Vec::with_capacity(MACHINE_LINES.len());
+
+            for (index, line) in machine_lines.iter().enumerate() {
+                let Some(&expected) = MACHINE_LINES.get(index) else {
+                    violations.push(Violation::at(
+                        Location::of(input, line),
+                        format!(
+                            "expected a blank line after the Prize line, found {:?}",
+                            line
+                        ),
+                    ));
+                    continue;
+                };
+
+                match parse_coordinates(input, line, coordinates_regex(expected), expected) {
+                    Ok(line_coordinates) => coordinates.push(line_coordinates),
+                    Err(error) => violations.push(Violation::from(error)),
+                }
+            }
+
+            if let Some(&missing) = MACHINE_LINES.get(machine_lines.len()) {
+                violations.push(Violation::at(
+                    Location::new(Location::of(input, last_line).line + 1, 1),
+                    ParseInputError::MissingLine(missing),
+                ));
+            }
+
+            // Cramer's rule needs the buttons to move in different directions.
+            if let [(button_a_x, button_a_y), (button_b_x, button_b_y), _] = coordinates[..] {
+                if button_a_x as u128 * button_b_y as u128
+                    == button_b_x as u128 * button_a_y as u128
+                {
+                    violations.push(Violation::at(
+                        Location::of(input, machine_lines[0]),
+                        "buttons A and B move in the same direction",
+                    ));
+                }
+            }
+        }
+
+        violations
+    }
+
+    fn part_one(machines: &Self::Input) -> Self::PartOne {
+        calculate_minimum_tokens_to_win_all_prizes(machines)
+    }
+
+    fn part_two(machines: &Self::Input) -> Self::PartTwo {
+        calculate_minimum_tokens_to_win_all_prizes_with_corrected_prize_positions(machines)
+    }
+}
+
+impl StreamingSolution for Day13 {
+    type RecordError = ParseInputError;
+
+    fn solve_reader(
+        reader: impl BufRead,
+    ) -> Result<(usize, usize), StreamError<Self::RecordError>> {
+        let mut coordinates = Vec::with_capacity(MACHINE_LINES.len());
+
+        let mut tokens = 0;
+        let mut tokens_with_corrected_prize_positions = 0;
+
+        let line_count = input::for_each_line(reader, |line| {
+            if line.is_empty() {
+                return Ok(());
+            }
+
+            let expected = MACHINE_LINES[coordinates.len()];
+
+            coordinates.push(parse_coordinates(
+                line,
+                line,
+                coordinates_regex(expected),
+                expected,
+            )?);
+
+            if let [(button_a_x, button_a_y), (button_b_x, button_b_y), (prize_x, prize_y)] =
+                coordinates[..]
+            {
+                let machine = Machine::new(
+                    Button::new(button_a_x, button_a_y),
+                    Button::new(button_b_x, button_b_y),
+                    Position::new(prize_x, prize_y),
+                );
+
+                tokens += minimum_tokens(&machine, MAX_PRESSES).unwrap_or(0);
+                tokens_with_corrected_prize_positions +=
+                    minimum_tokens(&machine.with_corrected_prize_position(), usize::MAX)
+                        .unwrap_or(0);
+
+                coordinates.clear();
+            }
+
+            Ok(())
+        })?;
+
+        if !coordinates.is_empty() {
+            return Err(StreamError::Parse(Located::new(
+                Location {
+                    line: line_count + 1,
+                    column: 1,
+                },
+                ParseInputError::MissingLine(MACHINE_LINES[coordinates.len()]),
+            )));
+        }
+
+        Ok((tokens, tokens_with_corrected_prize_positions))
+    }
+}
+
+/// The lines describing each machine, in order.
+const MACHINE_LINES: [&str; 3] = ["Button A", "Button B", "Prize"];
+
+const BUTTON_A_COST: usize = 3;
+const BUTTON_B_COST: usize = 1;
+pub const PRIZE_POSITION_CORRECTION: usize = 10000000000000;
+
+/// The most times each button can be pressed before the prize positions are corrected.
+const MAX_PRESSES: usize = 100;
+
+pub fn calculate_minimum_tokens_to_win_all_prizes(machines: &[Machine]) -> usize {
+    calculate_minimum_tokens(machines, MAX_PRESSES)
+}
+
+pub fn calculate_minimum_tokens_to_win_all_prizes_with_corrected_prize_positions(
+    machines: &[Machine],
+) -> usize {
+    let machines = machines
+        .iter()
+        .map(Machine::with_corrected_prize_position)
+        .collect::<Vec<_>>();
+
+    calculate_minimum_tokens(&machines, usize::MAX)
+}
+
+pub fn calculate_minimum_tokens(machines: &[Machine], max_presses: usize) -> usize {
+    machines
+        .iter()
+        .filter_map(|machine| minimum_tokens(machine, max_presses))
+        .sum()
+}
+
+/// The fewest tokens needed to win the machine's prize, if it can be won pressing neither button
+/// more than `max_presses` times.
+fn minimum_tokens(machine: &Machine, max_presses: usize) -> Option<usize> {
+    let button_a_x = machine.button_a.x as f64;
+    let button_a_y = machine.button_a.y as f64;
+    let button_b_x = machine.button_b.x as f64;
+    let button_b_y = machine.button_b.y as f64;
+    let prize_x = machine.prize.x as f64;
+    let prize_y = machine.prize.y as f64;
+
+    // Eq 1: ax * na + bx * nb = px
+    // Eq 2: ay * na + by * nb = py
+
+    // Cramer's rule:
+
+    // Matrix:
+    // ax bx
+    // ay by
+
+    let determinant = button_a_x * button_b_y - button_b_x * button_a_y;
+
+    // A Matrix:
+    // px bx
+    // py by
+
+    let determinant_a = prize_x * button_b_y - button_b_x * prize_y;
+
+    // B Matrix:
+    // ax px
+    // ay py
+
+    let determinant_b = button_a_x * prize_y - prize_x * button_a_y;
+
+    let a = determinant_a / determinant;
+    let b = determinant_b / determinant;
+
+    if a < 0.0 || b < 0.0 || a.trunc() != a || b.trunc() != b {
+        return None;
+    }
+
+    if a > max_presses as f64 || b > max_presses as f64 {
+        return None;
+    }
+
+    let result = a * BUTTON_A_COST as f64 + b * BUTTON_B_COST as f64;
+
+    Some(result as usize)
+}
+
+lazy_static! {
+    static ref BUTTON_A_REGEX: Regex = Regex::new(r"^Button A: X\+(\d+), Y\+(\d+)$").unwrap();
+    static ref BUTTON_B_REGEX: Regex = Regex::new(r"^Button B: X\+(\d+), Y\+(\d+)$").unwrap();
+    static ref PRIZE_REGEX: Regex = Regex::new(r"^Prize: X=(\d+), Y=(\d+)$").unwrap();
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseInputError {
+    MissingLine(&'static str),
+    Malformed {
+        expected: &'static str,
+        found: String,
+    },
+    InvalidNumber(String),
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseInputError::MissingLine(expected) => {
+                write!(f, "input ended before the {} line", expected)
+            }
+            ParseInputError::Malformed { expected, found } => {
+                write!(f, "expected a {} line, found {:?}", expected, found)
+            }
+            ParseInputError::InvalidNumber(text) => write!(f, "invalid number {:?}", text),
+        }
+    }
+}
+
+impl Error for ParseInputError {}
+
+pub fn parse_input(input: &str) -> Result<Vec<Machine>, Located<ParseInputError>> {
+    let mut machines = Vec::new();
+    let mut lines = input.lines().filter(|line| !line.is_empty());
+
+    while let Some(button_a_line) = lines.next() {
+        let mut next_line = |expected| {
+            lines.next().ok_or_else(|| {
+                Located::at(
+                    input,
+                    &input[input.len()..],
+                    ParseInputError::MissingLine(expected),
+                )
+            })
+        };
+
+        let button_b_line = next_line("Button B")?;
+        let prize_line = next_line("Prize")?;
+
+        let (button_a_x, button_a_y) =
+            parse_coordinates(input, button_a_line, &BUTTON_A_REGEX, "Button A")?;
+        let (button_b_x, button_b_y) =
+            parse_coordinates(input, button_b_line, &BUTTON_B_REGEX, "Button B")?;
+        let (prize_x, prize_y) = parse_coordinates(input, prize_line, &PRIZE_REGEX, "Prize")?;
+
+        machines.push(Machine::new(
+            Button::new(button_a_x, button_a_y),
+            Button::new(button_b_x, button_b_y),
+            Position::new(prize_x, prize_y),
+        ));
+    }
+
+    Ok(machines)
+}
+
+fn coordinates_regex(expected: &str) -> &'static Regex {
+    match expected {
+        "Button A" => &BUTTON_A_REGEX,
+        "Button B" => &BUTTON_B_REGEX,
+        _ => &PRIZE_REGEX,
+    }
+}
+
+fn parse_coordinates(
+    input: &str,
+    line: &str,
+    regex: &Regex,
+    expected: &'static str,
+) -> Result<(usize, usize), Located<ParseInputError>> {
+    let captures = regex.captures(line).ok_or_else(|| {
+        Located::at(
+            input,
+            line,
+            ParseInputError::Malformed {
+                expected,
+                found: line.to_string(),
+            },
+        )
+    })?;
+
+    let parse_number = |index| {
+        let text = captures.get(index).unwrap().as_str();
+
+        text.parse::<usize>().map_err(|_| {
+            Located::at(
+                input,
+                text,
+                ParseInputError::InvalidNumber(text.to_string()),
+            )
+        })
+    };
+
+    Ok((parse_number(1)?, parse_number(2)?))
+}
+
+#[derive(Debug)]
+pub struct Machine {
+    button_a: Button,
+    button_b: Button,
+    prize: Position,
+}
+
+impl Machine {
+    fn new(button_a: Button, button_b: Button, prize: Position) -> Self {
+        Self {
+            button_a,
+            button_b,
+            prize,
+        }
+    }
+
+    fn with_corrected_prize_position(&self) -> Self {
+        Self::new(
+            self.button_a.clone(),
+            self.button_b.clone(),
+            Position::new(
+                self.prize.x + PRIZE_POSITION_CORRECTION,
+                self.prize.y + PRIZE_POSITION_CORRECTION,
+            ),
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Button {
+    x: usize,
+    y: usize,
+}
+
+impl Button {
+    fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+#[derive(Debug)]
+struct Position {
+    x: usize,
+    y: usize,
+}
+
+impl Position {
+    fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("aoc-input/example-input.txt");
+
+    #[test]
+    fn calculate_minimum_tokens_to_win_all_prizes_works() {
+        assert_eq!(
+            calculate_minimum_tokens_to_win_all_prizes(&parse_input(EXAMPLE_INPUT).unwrap()),
+            480
+        );
+    }
+
+    #[test]
+    fn parse_input_rejects_malformed_input() {
+        assert_eq!(
+            parse_input("Button A: X+94, Y+34\nButton B: X+22, Y+67\n")
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 1: input ended before the Prize line"
+        );
+        assert_eq!(
+            parse_input("Button A: X+94, Y+34\nButton B: X-22, Y+67\nPrize: X=8400, Y=5400\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: expected a Button B line, found \"Button B: X-22, Y+67\""
+        );
+    }
+
+    #[test]
+    fn solve_reader_works() {
+        assert_eq!(
+            Day13::solve_reader(EXAMPLE_INPUT.as_bytes()).unwrap(),
+            (480, 875318608908)
+        );
+
+        let repeated = [EXAMPLE_INPUT; 1000].join("\n");
+        assert_eq!(
+            Day13::solve_reader(repeated.as_bytes()).unwrap(),
+            (480 * 1000, 875318608908 * 1000)
+        );
+
+        assert_eq!(
+            Day13::solve_reader("Button A: X+94, Y+34\nButton B: X+22, Y+67\n".as_bytes())
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 1: input ended before the Prize line"
+        );
+    }
+
+    /// Tries every number of presses of each button up to 100, the most part one allows.
+    fn naive_minimum_tokens(
+        button_a: (usize, usize),
+        button_b: (usize, usize),
+        prize: (usize, usize),
+    ) -> Option<usize> {
+        (0..=100)
+            .flat_map(|a| (0..=100).map(move |b| (a, b)))
+            .filter(|(a, b)| {
+                a * button_a.0 + b * button_b.0 == prize.0
+                    && a * button_a.1 + b * button_b.1 == prize.1
+            })
+            .map(|(a, b)| a * BUTTON_A_COST + b * BUTTON_B_COST)
+            .min()
+    }
+
+    fn machine() -> impl Strategy<Value = ((usize, usize), (usize, usize), (usize, usize))> {
+        let button = || (1..100usize, 1..100usize);
+
+        (button(), button())
+            .prop_flat_map(|(button_a, button_b)| {
+                // Half the prizes are reachable, some of them only with more than 100 presses.
+                let presses = (0..=150usize, 0..=150usize).prop_map(move |(a, b)| {
+                    (
+                        a * button_a.0 + b * button_b.0,
+                        a * button_a.1 + b * button_b.1,
+                    )
+                });
+                let anywhere = (0..20000usize, 0..20000usize);
+
+                (
+                    Just(button_a),
+                    Just(button_b),
+                    prop_oneof![presses, anywhere],
+                )
+            })
+            // Cramer's rule needs buttons that don't move the claw in the same direction, which the
+            // real input guarantees.
+            .prop_filter("buttons are parallel", |(button_a, button_b, _)| {
+                button_a.0 * button_b.1 != button_a.1 * button_b.0
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn calculate_minimum_tokens_matches_pressing_every_combination(
+            (button_a, button_b, prize) in machine()
+        ) {
+            let input = format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                button_a.0, button_a.1, button_b.0, button_b.1, prize.0, prize.1
+            );
+
+            prop_assert_eq!(
+                calculate_minimum_tokens_to_win_all_prizes(&parse_input(&input).unwrap()),
+                naive_minimum_tokens(button_a, button_b, prize).unwrap_or(0),
+                "input:\n{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn check_works() {
+        assert!(Day13::check(EXAMPLE_INPUT).is_empty());
+        assert_eq!(
+            Day13::check(
+                "Button A: X+94, Y+34\nButton B: X+22, Y+67\n\n\
+                 Button A: X+1, Y+2\nButton B: X-22, Y+67\nPrize: X=1, Y=2\nPrize: X=1, Y=2\n\n\
+                 Button A: X+2, Y+4\nButton B: X+1, Y+2\nPrize: X=3, Y=6\n"
+            ),
+            vec![
+                Violation::at(Location::new(3, 1), "input ended before the Prize line"),
+                Violation::at(
+                    Location::new(5, 1),
+                    "expected a Button B line, found \"Button B: X-22, Y+67\""
+                ),
+                Violation::at(
+                    Location::new(7, 1),
+                    "expected a blank line after the Prize line, found \"Prize: X=1, Y=2\""
+                ),
+                Violation::at(
+                    Location::new(9, 1),
+                    "buttons A and B move in the same direction"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_swapped_buttons_works() {
+        assert_eq!(
+            Day13::check("Button B: X+22, Y+67\nButton A: X+94, Y+34\nPrize: X=8400, Y=5400\n"),
+            vec![
+                Violation::at(
+                    Location::new(1, 1),
+                    "expected a Button A line, found \"Button B: X+22, Y+67\""
+                ),
+                Violation::at(
+                    Location::new(2, 1),
+                    "expected a Button B line, found \"Button A: X+94, Y+34\""
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,442 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io,
+    str::FromStr,
+};
+
+use common::{pool::Pool, Located, Location, Part, Solution, Violation};
+use geometry::{Position, Vector};
+use grid::Grid;
+use visualizer::{render, Cell, Colour, Frames};
+
+pub const AREA_WIDTH: u16 = 101;
+pub const AREA_HEIGHT: u16 = 103;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    const INTERACTIVE_PARTS: &'static [Part] = &[Part::Two];
+
+    type Input = Vec<Robot>;
+    type ParseError = Located<ParseInputError>;
+    type PartOne = u32;
+    type PartTwo = Option<u16>;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        parse_input(input)
+    }
+
+    fn check(input: &str) -> Vec<Violation> {
+        input
+            .lines()
+            .filter_map(|line| match parse_line(input, line) {
+                Err(error) => Some(Violation::from(error)),
+                Ok(robot)
+                    if robot.position.x >= usize::from(AREA_WIDTH)
+                        || robot.position.y >= usize::from(AREA_HEIGHT) =>
+                {
+                    Some(Violation::at(
+                        Location::of(input, line),
+                        format!(
+                            "robot starts at {},{}, outside the {}x{} area",
+                            robot.position.x, robot.position.y, AREA_WIDTH, AREA_HEIGHT
+                        ),
+                    ))
+                }
+                Ok(_) => None,
+            })
+            .collect()
+    }
+
+    fn part_one(robots: &Self::Input) -> Self::PartOne {
+        calculate_safety_factor_after_seconds(robots, 100, AREA_WIDTH, AREA_HEIGHT)
+    }
+
+    fn part_two(robots: &Self::Input) -> Self::PartTwo {
+        render_robot_movements_until_christmas_tree(robots, AREA_WIDTH, AREA_HEIGHT)
+    }
+}
+
+pub fn calculate_safety_factor_after_seconds(
+    robots: &[Robot],
+    seconds: u16,
+    area_width: u16,
+    area_height: u16,
+) -> u32 {
+    let mut robots = robots.to_vec();
+
+    for robot in robots.as_mut_slice() {
+        robot.simulate_movement(seconds, area_width, area_height);
+    }
+
+    calculate_safety_factor(&robots, area_width, area_height)
+}
+
+/// How many seconds it takes every robot to be back where it started, whatever its velocity, as
+/// each one's x repeats every `area_width` seconds and its y every `area_height`. The arrangements
+/// in between are the only ones there are, so searching further would only repeat them.
+pub fn repeat_period(area_width: u16, area_height: u16) -> u16 {
+    let (mut a, mut b) = (u32::from(area_width), u32::from(area_height));
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    (u32::from(area_width) * u32::from(area_height) / a)
+        .try_into()
+        .unwrap_or(u16::MAX)
+}
+
+/// Shows the arrangement after each second in the order most likely to be the Christmas tree,
+/// until the user spots it. Only the first [`repeat_period`] seconds are searched, so this gives
+/// up with `None` once every arrangement has been shown.
+pub fn render_robot_movements_until_christmas_tree(
+    robots: &[Robot],
+    area_width: u16,
+    area_height: u16,
+) -> Option<u16> {
+    let seconds = (1..=repeat_period(area_width, area_height)).collect::<Vec<_>>();
+
+    let mut safety_factors = Pool::default().map(&seconds, |&i| {
+        (
+            i,
+            calculate_safety_factor_after_seconds(robots, i, area_width, area_height),
+        )
+    });
+
+    safety_factors.sort_by_key(|&(_, safety_factor)| safety_factor);
+
+    for (i, _) in safety_factors {
+        let mut robots = robots.to_vec();
+        for robot in robots.as_mut_slice() {
+            robot.simulate_movement(i, area_width, area_height);
+        }
+
+        render_robots(&robots, area_width, area_height);
+
+        println!("Is this a Christmas tree? [i = {}] (y/N)", i);
+        let mut input = String::new();
+        io::stdin()
+            .read_line(&mut input)
+            .expect("user input expected to succeed");
+        if input.to_lowercase().trim_start().starts_with('y') {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+pub fn calculate_safety_factor(robots: &[Robot], area_width: u16, area_height: u16) -> u32 {
+    let x_midpoint = usize::from(area_width / 2);
+    let y_midpoint = usize::from(area_height / 2);
+
+    let mut quadrant_counts: [u16; 4] = [0, 0, 0, 0];
+
+    for robot in robots {
+        let quadrant = match robot.position {
+            Position { x, y } if x < x_midpoint && y < y_midpoint => 0,
+            Position { x, y } if x > x_midpoint && y < y_midpoint => 1,
+            Position { x, y } if x < x_midpoint && y > y_midpoint => 2,
+            Position { x, y } if x > x_midpoint && y > y_midpoint => 3,
+            _ => continue,
+        };
+
+        *quadrant_counts.get_mut(quadrant).unwrap() += 1;
+    }
+
+    quadrant_counts.iter().map(|&x| x as u32).product()
+}
+
+fn render_robots(robots: &[Robot], area_width: u16, area_height: u16) {
+    print!("{}", render(&robot_grid(robots, area_width, area_height)));
+}
+
+fn robot_grid(robots: &[Robot], area_width: u16, area_height: u16) -> Grid<RobotCell> {
+    let mut grid = Grid::new(area_width.into(), area_height.into(), RobotCell::Empty);
+
+    for robot in robots {
+        grid[robot.position] = match grid[robot.position] {
+            RobotCell::Empty => RobotCell::Robots(1),
+            RobotCell::Robots(count) => RobotCell::Robots(count + 1),
+        };
+    }
+
+    grid
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RobotCell {
+    Empty,
+    Robots(usize),
+}
+
+impl Cell for RobotCell {
+    fn symbol(&self) -> char {
+        match self {
+            RobotCell::Empty => ' ',
+            RobotCell::Robots(_) => '█',
+        }
+    }
+
+    fn colour(&self) -> Colour {
+        match self {
+            RobotCell::Empty => Colour::Default,
+            RobotCell::Robots(_) => Colour::Green,
+        }
+    }
+}
+
+/// The robots' positions after each second, one second per frame, until they repeat.
+pub struct RobotMovements {
+    robots: Vec<Robot>,
+    area_width: u16,
+    area_height: u16,
+}
+
+impl RobotMovements {
+    pub fn new(robots: &[Robot], area_width: u16, area_height: u16) -> Self {
+        Self {
+            robots: robots.to_vec(),
+            area_width,
+            area_height,
+        }
+    }
+}
+
+impl Frames for RobotMovements {
+    type Cell = RobotCell;
+
+    fn frame_count(&self) -> usize {
+        usize::from(repeat_period(self.area_width, self.area_height))
+    }
+
+    fn frame(&mut self, index: usize) -> Grid<Self::Cell> {
+        let seconds = u16::try_from(index).unwrap();
+
+        let mut robots = self.robots.clone();
+        for robot in robots.as_mut_slice() {
+            robot.simulate_movement(seconds, self.area_width, self.area_height);
+        }
+
+        robot_grid(&robots, self.area_width, self.area_height)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Robot {
+    position: Position,
+    velocity: Vector,
+}
+
+impl Robot {
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    pub fn velocity(&self) -> Vector {
+        self.velocity
+    }
+
+    pub fn simulate_movement(&mut self, seconds: u16, area_width: u16, area_height: u16) {
+        self.position = Position::new(
+            wrap_movement(self.position.x, self.velocity.x, seconds, area_width),
+            wrap_movement(self.position.y, self.velocity.y, seconds, area_height),
+        );
+    }
+}
+
+/// Where a robot at `position` on one axis ends up after `seconds`, wrapping around `size`. The
+/// position and velocity are wrapped first, so any values the input holds can't overflow.
+fn wrap_movement(position: usize, velocity: isize, seconds: u16, size: u16) -> usize {
+    let size = u64::from(size);
+    let position = position as u64 % size;
+    let velocity = velocity.rem_euclid(size as isize) as u64;
+
+    ((position + velocity * u64::from(seconds)) % size) as usize
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseInputError {
+    Malformed(String),
+    InvalidNumber(String),
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseInputError::Malformed(line) => {
+                write!(f, "expected a robot like p=0,4 v=3,-3, found {:?}", line)
+            }
+            ParseInputError::InvalidNumber(text) => write!(f, "invalid number {:?}", text),
+        }
+    }
+}
+
+impl Error for ParseInputError {}
+
+pub fn parse_input(input: &str) -> Result<Vec<Robot>, Located<ParseInputError>> {
+    input.lines().map(|line| parse_line(input, line)).collect()
+}
+
+fn parse_line(input: &str, line: &str) -> Result<Robot, Located<ParseInputError>> {
+    let malformed = || Located::at(input, line, ParseInputError::Malformed(line.to_string()));
+
+    let (position, velocity) = line
+        .strip_prefix("p=")
+        .and_then(|rest| rest.split_once(" v="))
+        .ok_or_else(malformed)?;
+
+    let (position_x, position_y) = position.split_once(',').ok_or_else(malformed)?;
+    let (velocity_x, velocity_y) = velocity.split_once(',').ok_or_else(malformed)?;
+
+    Ok(Robot {
+        position: Position::new(
+            parse_number(input, position_x)?,
+            parse_number(input, position_y)?,
+        ),
+        velocity: Vector::new(
+            parse_number(input, velocity_x)?,
+            parse_number(input, velocity_y)?,
+        ),
+    })
+}
+
+fn parse_number<T: FromStr>(input: &str, text: &str) -> Result<T, Located<ParseInputError>> {
+    text.parse().map_err(|_| {
+        Located::at(
+            input,
+            text,
+            ParseInputError::InvalidNumber(text.to_string()),
+        )
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("aoc-input/example-input.txt");
+
+    #[test]
+    fn calculate_safety_factor_after_seconds_works() {
+        assert_eq!(
+            calculate_safety_factor_after_seconds(&parse_input(EXAMPLE_INPUT).unwrap(), 100, 11, 7),
+            12
+        );
+    }
+
+    #[test]
+    fn robot_movements_works() {
+        let mut robot_movements = RobotMovements::new(&parse_input(EXAMPLE_INPUT).unwrap(), 11, 7);
+
+        // The robots are back where they started after 77 seconds, so frame 23 shows them after
+        // 100 seconds.
+        assert_eq!(robot_movements.frame_count(), 77);
+        assert_eq!(RobotMovements::new(&[], 4, 6).frame_count(), 12);
+        assert_eq!(
+            robot_movements
+                .frame(23)
+                .iter()
+                .filter(|(_, cell)| **cell != RobotCell::Empty)
+                .count(),
+            10
+        );
+        assert_eq!(
+            robot_movements.frame(23)[Position::new(6, 0)],
+            RobotCell::Robots(2)
+        );
+    }
+
+    #[test]
+    fn repeat_period_works() {
+        assert_eq!(repeat_period(AREA_WIDTH, AREA_HEIGHT), 10403);
+        assert_eq!(repeat_period(11, 7), 77);
+        assert_eq!(repeat_period(4, 6), 12);
+
+        for robot in parse_input(EXAMPLE_INPUT).unwrap() {
+            let mut moved = robot.clone();
+            moved.simulate_movement(repeat_period(11, 7), 11, 7);
+            assert_eq!(moved.position, robot.position);
+        }
+    }
+
+    #[test]
+    fn simulate_robot_movement_works() {
+        let mut robot = Robot {
+            position: Position::new(2, 4),
+            velocity: Vector::new(2, -3),
+        };
+        robot.simulate_movement(1, 11, 7);
+        assert_eq!(robot.position, Position::new(4, 1));
+        robot.simulate_movement(1, 11, 7);
+        assert_eq!(robot.position, Position::new(6, 5));
+        robot.simulate_movement(1, 11, 7);
+        assert_eq!(robot.position, Position::new(8, 2));
+        robot.simulate_movement(1, 11, 7);
+        assert_eq!(robot.position, Position::new(10, 6));
+        robot.simulate_movement(1, 11, 7);
+        assert_eq!(robot.position, Position::new(1, 3));
+    }
+
+    #[test]
+    fn simulate_robot_movement_handles_large_values() {
+        let mut robots = parse_input("p=0,0 v=9223372036854775807,1\np=1000,0 v=0,-1").unwrap();
+        for robot in robots.as_mut_slice() {
+            robot.simulate_movement(100, 11, 7);
+        }
+
+        assert_eq!(robots[0].position, Position::new(7, 2));
+        assert_eq!(robots[1].position, Position::new(10, 5));
+    }
+
+    proptest! {
+        #[test]
+        fn simulate_movement_is_additive(
+            x in 0..AREA_WIDTH as usize,
+            y in 0..AREA_HEIGHT as usize,
+            velocity_x in -100..=100isize,
+            velocity_y in -100..=100isize,
+            a in 0..u16::MAX / 2,
+            b in 0..u16::MAX / 2,
+        ) {
+            let robot = Robot {
+                position: Position::new(x, y),
+                velocity: Vector::new(velocity_x, velocity_y),
+            };
+
+            let mut stepwise = robot.clone();
+            stepwise.simulate_movement(a, AREA_WIDTH, AREA_HEIGHT);
+            stepwise.simulate_movement(b, AREA_WIDTH, AREA_HEIGHT);
+
+            let mut at_once = robot;
+            at_once.simulate_movement(a + b, AREA_WIDTH, AREA_HEIGHT);
+
+            prop_assert_eq!(stepwise.position, at_once.position);
+            prop_assert!(at_once.position.x < AREA_WIDTH as usize);
+            prop_assert!(at_once.position.y < AREA_HEIGHT as usize);
+        }
+    }
+
+    #[test]
+    fn check_works() {
+        assert!(Day14::check(EXAMPLE_INPUT).is_empty());
+        assert_eq!(
+            Day14::check("p=0,4 v=3,-3\np=101,4 v=3,-3\np=0,4\np=-1,4 v=3,-3\n"),
+            vec![
+                Violation::at(
+                    Location::new(2, 1),
+                    "robot starts at 101,4, outside the 101x103 area"
+                ),
+                Violation::at(
+                    Location::new(3, 1),
+                    "expected a robot like p=0,4 v=3,-3, found \"p=0,4\""
+                ),
+                Violation::at(Location::new(4, 3), "invalid number \"-1\""),
+            ]
+        );
+    }
+}
@@ -0,0 +1,541 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    error::Error,
+    fmt::{self, Display},
+};
+
+use common::{Location, Solution, Violation};
+use geometry::{Direction, Position};
+use grid::{check_grid, Grid, ParseGridError};
+use tracing::trace;
+use visualizer::{Cell, Colour, Frames};
+
+/// The characters a warehouse map can hold. Wide boxes only appear once it is scaled up.
+const WAREHOUSE_CHARACTERS: &str = "#.O@";
+
+/// How many movements apart [`WarehouseMovements`] keeps copies of the warehouse.
+const CHECKPOINT_INTERVAL: usize = 64;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = (Warehouse, Vec<Direction>);
+    type ParseError = ParseInputError;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        parse_input(input)
+    }
+
+    fn check(input: &str) -> Vec<Violation> {
+        let lines = input.lines().collect::<Vec<_>>();
+        let warehouse_height = lines
+            .iter()
+            .position(|line| line.is_empty())
+            .unwrap_or(lines.len());
+        let (warehouse, movements) = lines.split_at(warehouse_height);
+
+        let mut violations = check_grid(&warehouse.join("\n"), |character| {
+            WAREHOUSE_CHARACTERS.contains(character)
+        })
+        .into_iter()
+        .map(Violation::from)
+        .collect::<Vec<_>>();
+
+        let mut robot_locations = Vec::new();
+
+        for (y, line) in warehouse.iter().enumerate() {
+            let width = line.chars().count();
+
+            for (x, character) in line.chars().enumerate() {
+                let location = Location::new(y + 1, x + 1);
+
+                if character == '@' {
+                    robot_locations.push(location);
+                }
+
+                let on_edge = y == 0 || y == warehouse_height - 1 || x == 0 || x == width - 1;
+                if on_edge && character != '#' && WAREHOUSE_CHARACTERS.contains(character) {
+                    violations.push(Violation::at(
+                        location,
+                        format!("expected a wall '#' on the edge, found {:?}", character),
+                    ));
+                }
+            }
+        }
+
+        match robot_locations.split_first() {
+            None => violations.push(Violation::anywhere(ParseInputError::MissingRobot)),
+            Some((_, extra_robot_locations)) => {
+                violations.extend(
+                    extra_robot_locations
+                        .iter()
+                        .map(|&location| Violation::at(location, "found a second robot '@'")),
+                );
+            }
+        }
+
+        for line in movements {
+            for (offset, character) in line.char_indices() {
+                if !"^v<>".contains(character) {
+                    violations.push(Violation::at(
+                        Location::of(input, &line[offset..]),
+                        format!("expected one of ^, v, < or >, found {:?}", character),
+                    ));
+                }
+            }
+        }
+
+        violations
+    }
+
+    fn part_one((warehouse, movements): &Self::Input) -> Self::PartOne {
+        sum_of_boxes_gps_coordinates(warehouse, movements)
+    }
+
+    fn part_two((warehouse, movements): &Self::Input) -> Self::PartTwo {
+        sum_of_boxes_gps_coordinates_scaled_up(warehouse, movements)
+    }
+}
+
+pub fn sum_of_boxes_gps_coordinates(warehouse: &Warehouse, movements: &[Direction]) -> usize {
+    let mut warehouse = warehouse.clone();
+
+    for (i, movement) in movements.iter().enumerate() {
+        warehouse.apply_robot_movement(movement);
+        trace_movement(i, movement, &warehouse);
+    }
+
+    warehouse.sum_of_boxes_gps_coordinates()
+}
+
+pub fn sum_of_boxes_gps_coordinates_scaled_up(
+    warehouse: &Warehouse,
+    movements: &[Direction],
+) -> usize {
+    let mut warehouse = warehouse.scaled_up();
+
+    for (i, movement) in movements.iter().enumerate() {
+        warehouse.apply_robot_movement(movement);
+        trace_movement(i, movement, &warehouse);
+    }
+
+    warehouse.sum_of_boxes_gps_coordinates()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tile {
+    Empty,
+    Wall,
+    Box,
+    BoxLeft,
+    BoxRight,
+}
+
+impl Tile {
+    pub fn as_char(&self) -> char {
+        match self {
+            Tile::Empty => '.',
+            Tile::Wall => '#',
+            Tile::Box => 'O',
+            Tile::BoxLeft => '[',
+            Tile::BoxRight => ']',
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Warehouse {
+    robot: Position,
+    tiles: Grid<Tile>,
+}
+
+impl Warehouse {
+    pub fn robot(&self) -> Position {
+        self.robot
+    }
+
+    pub fn tiles(&self) -> &Grid<Tile> {
+        &self.tiles
+    }
+
+    pub fn scaled_up(&self) -> Warehouse {
+        let mut tiles = Grid::new(self.tiles.width() * 2, self.tiles.height(), Tile::Empty);
+
+        for (position, tile) in self.tiles.iter() {
+            let left = Position::new(position.x * 2, position.y);
+            let right = Position::new(position.x * 2 + 1, position.y);
+
+            let (left_tile, right_tile) = match tile {
+                Tile::Box => (Tile::BoxLeft, Tile::BoxRight),
+                tile => (*tile, *tile),
+            };
+
+            tiles[left] = left_tile;
+            tiles[right] = right_tile;
+        }
+
+        Warehouse {
+            robot: Position::new(self.robot.x * 2, self.robot.y),
+            tiles,
+        }
+    }
+
+    pub fn apply_robot_movement(&mut self, movement: &Direction) {
+        let mut positions_to_check = VecDeque::from([translate(&self.robot, movement)]);
+        let mut checked_positions = HashSet::new();
+
+        let mut box_positions_to_move = Vec::new();
+
+        while let Some(position_to_check) = positions_to_check.pop_front() {
+            if !checked_positions.insert(position_to_check) {
+                continue;
+            }
+
+            match self.tiles[position_to_check] {
+                Tile::Empty => continue,
+                Tile::Wall => return,
+                Tile::Box => {}
+                Tile::BoxLeft => {
+                    positions_to_check.push_back(translate(&position_to_check, &Direction::Right));
+                }
+                Tile::BoxRight => {
+                    positions_to_check.push_back(translate(&position_to_check, &Direction::Left));
+                }
+            }
+
+            box_positions_to_move.push(position_to_check);
+            positions_to_check.push_back(translate(&position_to_check, movement));
+        }
+
+        self.robot = translate(&self.robot, movement);
+
+        let moved_tiles = box_positions_to_move
+            .iter()
+            .map(|position| (translate(position, movement), self.tiles[*position]))
+            .collect::<Vec<_>>();
+
+        for position in box_positions_to_move {
+            self.tiles[position] = Tile::Empty;
+        }
+
+        for (position, tile) in moved_tiles {
+            self.tiles[position] = tile;
+        }
+    }
+
+    fn cells(&self) -> Grid<WarehouseCell> {
+        let mut cells = self.tiles.map(|&tile| WarehouseCell::Tile(tile));
+        cells[self.robot] = WarehouseCell::Robot;
+
+        cells
+    }
+
+    pub fn sum_of_boxes_gps_coordinates(&self) -> usize {
+        self.tiles
+            .iter()
+            .filter(|(_, tile)| matches!(tile, Tile::Box | Tile::BoxLeft))
+            .map(|(position, _)| 100 * position.y + position.x)
+            .sum()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WarehouseCell {
+    Tile(Tile),
+    Robot,
+}
+
+impl Cell for WarehouseCell {
+    fn symbol(&self) -> char {
+        match self {
+            WarehouseCell::Tile(tile) => tile.as_char(),
+            WarehouseCell::Robot => '@',
+        }
+    }
+
+    fn colour(&self) -> Colour {
+        match self {
+            WarehouseCell::Tile(Tile::Empty) => Colour::Grey,
+            WarehouseCell::Tile(Tile::Wall) => Colour::White,
+            WarehouseCell::Tile(_) => Colour::Yellow,
+            WarehouseCell::Robot => Colour::Red,
+        }
+    }
+}
+
+/// The warehouse before and after each of the robot's movements, one movement per frame.
+pub struct WarehouseMovements {
+    movements: Vec<Direction>,
+    /// The warehouse after every [`CHECKPOINT_INTERVAL`] movements, so that a frame can be
+    /// replayed from the closest one instead of from the start.
+    checkpoints: Vec<Warehouse>,
+}
+
+impl WarehouseMovements {
+    pub fn new(warehouse: &Warehouse, movements: &[Direction]) -> Self {
+        let mut warehouse = warehouse.clone();
+        let mut checkpoints = vec![warehouse.clone()];
+
+        for (i, movement) in movements.iter().enumerate() {
+            warehouse.apply_robot_movement(movement);
+
+            if (i + 1) % CHECKPOINT_INTERVAL == 0 {
+                checkpoints.push(warehouse.clone());
+            }
+        }
+
+        Self {
+            movements: movements.to_vec(),
+            checkpoints,
+        }
+    }
+}
+
+impl Frames for WarehouseMovements {
+    type Cell = WarehouseCell;
+
+    fn frame_count(&self) -> usize {
+        self.movements.len() + 1
+    }
+
+    fn frame(&mut self, index: usize) -> Grid<Self::Cell> {
+        let checkpoint = index / CHECKPOINT_INTERVAL;
+        let mut warehouse = self.checkpoints[checkpoint].clone();
+
+        for movement in &self.movements[checkpoint * CHECKPOINT_INTERVAL..index] {
+            warehouse.apply_robot_movement(movement);
+        }
+
+        warehouse.cells()
+    }
+}
+
+fn translate(position: &Position, direction: &Direction) -> Position {
+    position.translate(*direction).unwrap()
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseInputError {
+    Warehouse(ParseGridError),
+    MissingRobot,
+    MultipleRobots(Location),
+    MissingWall { location: Location, character: char },
+    InvalidMovement { location: Location, character: char },
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseInputError::Warehouse(error) => write!(f, "{}", error),
+            ParseInputError::MissingRobot => write!(f, "no robot '@' found in the warehouse"),
+            ParseInputError::MultipleRobots(location) => {
+                write!(f, "{}: found a second robot '@'", location)
+            }
+            ParseInputError::MissingWall {
+                location,
+                character,
+            } => write!(
+                f,
+                "{}: expected a wall '#' on the edge, found {:?}",
+                location, character
+            ),
+            ParseInputError::InvalidMovement {
+                location,
+                character,
+            } => write!(
+                f,
+                "{}: expected one of ^, v, < or >, found {:?}",
+                location, character
+            ),
+        }
+    }
+}
+
+impl Error for ParseInputError {}
+
+pub fn parse_input(input: &str) -> Result<(Warehouse, Vec<Direction>), ParseInputError> {
+    let mut lines = input.lines();
+
+    let warehouse = lines
+        .by_ref()
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let warehouse = Grid::parse_with(&warehouse, |character| {
+        WAREHOUSE_CHARACTERS
+            .contains(character)
+            .then_some(character)
+    })
+    .map_err(ParseInputError::Warehouse)?;
+
+    let mut robots = warehouse.positions_of(&'@');
+    let robot = robots.next().ok_or(ParseInputError::MissingRobot)?;
+    if let Some(position) = robots.next() {
+        return Err(ParseInputError::MultipleRobots(Location::new(
+            position.y + 1,
+            position.x + 1,
+        )));
+    }
+
+    // The robot never leaves the warehouse, as the walls around its edge stop it.
+    if let Some((position, &character)) = warehouse.iter().find(|(position, character)| {
+        let on_edge = position.x == 0
+            || position.y == 0
+            || position.x == warehouse.width() - 1
+            || position.y == warehouse.height() - 1;
+
+        on_edge && **character != '#'
+    }) {
+        return Err(ParseInputError::MissingWall {
+            location: Location::new(position.y + 1, position.x + 1),
+            character,
+        });
+    }
+
+    let tiles = warehouse.map(|character| match character {
+        '#' => Tile::Wall,
+        'O' => Tile::Box,
+        _ => Tile::Empty,
+    });
+
+    let mut robot_movements = Vec::new();
+
+    for line in lines {
+        for (offset, character) in line.char_indices() {
+            let movement = match character {
+                '^' => Direction::Up,
+                'v' => Direction::Down,
+                '<' => Direction::Left,
+                '>' => Direction::Right,
+                _ => {
+                    return Err(ParseInputError::InvalidMovement {
+                        location: Location::of(input, &line[offset..]),
+                        character,
+                    })
+                }
+            };
+
+            robot_movements.push(movement);
+        }
+    }
+
+    Ok((Warehouse { robot, tiles }, robot_movements))
+}
+
+/// Logs the warehouse after each movement at trace level, for `-vvv`. The warehouse is only drawn
+/// when trace logging is enabled.
+fn trace_movement(i: usize, movement: &Direction, warehouse: &Warehouse) {
+    trace!(
+        movement = i,
+        direction = ?movement,
+        "warehouse after movement:\n{}",
+        warehouse.cells().map(Cell::symbol)
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_INPUT_1: &str = include_str!("aoc-input/example-input-1.txt");
+    const EXAMPLE_INPUT_2: &str = include_str!("aoc-input/example-input-2.txt");
+
+    #[test]
+    fn sum_of_boxes_gps_coordinates_works() {
+        let (warehouse_1, movements_1) = parse_input(EXAMPLE_INPUT_1).unwrap();
+        let (warehouse_2, movements_2) = parse_input(EXAMPLE_INPUT_2).unwrap();
+
+        assert_eq!(
+            sum_of_boxes_gps_coordinates(&warehouse_1, &movements_1),
+            10092
+        );
+        assert_eq!(
+            sum_of_boxes_gps_coordinates(&warehouse_2, &movements_2),
+            2028
+        );
+    }
+
+    #[test]
+    fn sum_of_boxes_gps_coordinates_scaled_up_works() {
+        let (warehouse, movements) = parse_input(EXAMPLE_INPUT_1).unwrap();
+
+        assert_eq!(
+            sum_of_boxes_gps_coordinates_scaled_up(&warehouse, &movements),
+            9021
+        )
+    }
+
+    #[test]
+    fn warehouse_movements_works() {
+        let (warehouse, movements) = parse_input(EXAMPLE_INPUT_1).unwrap();
+
+        let mut warehouse_movements = WarehouseMovements::new(&warehouse, &movements);
+        assert_eq!(warehouse_movements.frame_count(), movements.len() + 1);
+
+        let mut expected_warehouse = warehouse.clone();
+        for movement in &movements[..70] {
+            expected_warehouse.apply_robot_movement(movement);
+        }
+
+        assert_eq!(warehouse_movements.frame(0), warehouse.cells());
+        assert_eq!(warehouse_movements.frame(70), expected_warehouse.cells());
+    }
+
+    #[test]
+    fn parse_input_rejects_malformed_input() {
+        assert_eq!(
+            parse_input("###\n#.#\n###\n\n<>").err(),
+            Some(ParseInputError::MissingRobot)
+        );
+        assert_eq!(
+            parse_input("####\n#@O#\n####\n\n<>\n^x").err(),
+            Some(ParseInputError::InvalidMovement {
+                location: Location::new(6, 2),
+                character: 'x'
+            })
+        );
+        assert_eq!(
+            parse_input("#@.#\n####\n\n^\n").err(),
+            Some(ParseInputError::MissingWall {
+                location: Location::new(1, 2),
+                character: '@'
+            })
+        );
+        assert_eq!(
+            parse_input("######\n#@[]##\n######\n\n>")
+                .err()
+                .map(|error| error.to_string()),
+            Some(String::from("unexpected character '[' at line 2, column 3"))
+        );
+    }
+
+    #[test]
+    fn check_works() {
+        assert!(Day15::check(EXAMPLE_INPUT_1).is_empty());
+        assert!(Day15::check(EXAMPLE_INPUT_2).is_empty());
+        assert_eq!(
+            Day15::check("####\n#@O.\n#x@#\n####\n\n<>\n^x"),
+            vec![
+                Violation::at(Location::new(3, 2), "unexpected character 'x'"),
+                Violation::at(
+                    Location::new(2, 4),
+                    "expected a wall '#' on the edge, found '.'"
+                ),
+                Violation::at(Location::new(3, 3), "found a second robot '@'"),
+                Violation::at(
+                    Location::new(7, 2),
+                    "expected one of ^, v, < or >, found 'x'"
+                ),
+            ]
+        );
+        assert_eq!(
+            Day15::check("###\n#.#\n###\n\n<>"),
+            vec![Violation::anywhere("no robot '@' found in the warehouse")]
+        );
+    }
+}
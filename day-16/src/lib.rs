@@ -0,0 +1,575 @@
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt::{self, Display},
+};
+
+use common::{Location, Solution, Violation};
+use geometry::{Direction, Position};
+use grid::{check_grid, Grid, ParseGridError};
+use pathfinding::prelude::*;
+use visualizer::{Cell, Colour, Frames};
+
+/// The characters a maze can hold.
+const MAZE_CHARACTERS: &str = "#.SE";
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Maze;
+    type ParseError = ParseInputError;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        parse_input(input)
+    }
+
+    fn check(input: &str) -> Vec<Violation> {
+        let mut violations = check_grid(input, |character| MAZE_CHARACTERS.contains(character))
+            .into_iter()
+            .map(Violation::from)
+            .collect::<Vec<_>>();
+
+        let height = input.lines().count();
+        let mut start_locations = Vec::new();
+        let mut end_locations = Vec::new();
+
+        for (y, line) in input.lines().enumerate() {
+            let width = line.chars().count();
+
+            for (x, character) in line.chars().enumerate() {
+                let location = Location::new(y + 1, x + 1);
+
+                match character {
+                    'S' => start_locations.push(location),
+                    'E' => end_locations.push(location),
+                    _ => {}
+                }
+
+                let on_edge = y == 0 || y == height - 1 || x == 0 || x == width - 1;
+                if on_edge && character != '#' && MAZE_CHARACTERS.contains(character) {
+                    violations.push(Violation::at(
+                        location,
+                        format!("expected a wall '#' on the edge, found {:?}", character),
+                    ));
+                }
+            }
+        }
+
+        if let Ok(maze) = parse_input(input) {
+            if !is_end_reachable(&maze) {
+                violations.push(Violation::at(
+                    Location::new(maze.end.y + 1, maze.end.x + 1),
+                    "the 'E' tile can't be reached from the 'S' tile",
+                ));
+            }
+        }
+
+        for (character, locations) in [('S', start_locations), ('E', end_locations)] {
+            match locations.split_first() {
+                None => violations.push(Violation::anywhere(ParseInputError::Missing(character))),
+                Some((_, duplicate_locations)) => {
+                    violations.extend(duplicate_locations.iter().map(|&location| {
+                        Violation::at(location, format!("found a second {:?} tile", character))
+                    }));
+                }
+            }
+        }
+
+        violations
+    }
+
+    fn part_one(maze: &Self::Input) -> Self::PartOne {
+        calculate_lowest_score(maze)
+    }
+
+    fn part_two(maze: &Self::Input) -> Self::PartTwo {
+        count_tiles_on_best_paths(maze)
+    }
+}
+
+/// The lowest score of any path from the start to the end, or `None` if walls cut the end off.
+pub fn calculate_lowest_score(maze: &Maze) -> Option<u32> {
+    let (_path, score) = dijkstra(
+        &(maze.start, Direction::Right),
+        |state| successors(maze, state),
+        |(position, _direction)| maze.is_end(position),
+    )?;
+
+    Some(score)
+}
+
+/// How many tiles are on any of the best paths, or `None` if the end can't be reached.
+pub fn count_tiles_on_best_paths(maze: &Maze) -> Option<usize> {
+    let mut visited_tiles = HashSet::new();
+
+    for path in best_paths(maze)? {
+        for (tile, _direction) in path {
+            visited_tiles.insert(tile);
+        }
+    }
+
+    Some(visited_tiles.len())
+}
+
+fn best_paths(maze: &Maze) -> Option<impl Iterator<Item = Vec<(Position, Direction)>>> {
+    let (paths, _score) = astar_bag(
+        &(maze.start, Direction::Right),
+        |state| successors(maze, state),
+        |_| 0,
+        |(position, _direction)| maze.is_end(position),
+    )?;
+
+    Some(paths)
+}
+
+/// Whether any path of open tiles leads from the start to the end, found with a flood fill that
+/// ignores turning costs, so it is much cheaper than scoring the paths.
+fn is_end_reachable(maze: &Maze) -> bool {
+    bfs_reach(maze.start, |position| {
+        Direction::orthogonal()
+            .into_iter()
+            .filter_map(|direction| maze.step(position, &direction))
+            .collect::<Vec<_>>()
+    })
+    .any(|position| maze.is_end(&position))
+}
+
+/// The reindeer can turn 90 degrees for 1000 points or step forward for 1 point.
+fn successors(
+    maze: &Maze,
+    (position, direction): &(Position, Direction),
+) -> Vec<((Position, Direction), u32)> {
+    let mut successors = vec![
+        ((*position, direction.turn_left()), 1000),
+        ((*position, direction.turn_right()), 1000),
+    ];
+
+    if let Some(next_position) = maze.step(position, direction) {
+        successors.push(((next_position, *direction), 1));
+    }
+
+    successors
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tile {
+    Wall,
+    Empty,
+}
+
+pub struct Maze {
+    start: Position,
+    end: Position,
+    tiles: Grid<Tile>,
+}
+
+impl Maze {
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    pub fn tiles(&self) -> &Grid<Tile> {
+        &self.tiles
+    }
+
+    pub fn is_end(&self, position: &Position) -> bool {
+        position == &self.end
+    }
+
+    /// Returns the position one step away in `direction`, unless it is a wall.
+    pub fn step(&self, position: &Position, direction: &Direction) -> Option<Position> {
+        position
+            .translate(*direction)
+            .filter(|&next_position| self.tiles.get(next_position) == Some(&Tile::Empty))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MazeCell {
+    Wall,
+    Empty,
+    Start,
+    End,
+    Visited,
+    BestPath,
+    Reindeer(Direction),
+}
+
+impl Cell for MazeCell {
+    fn symbol(&self) -> char {
+        match self {
+            MazeCell::Wall => '#',
+            MazeCell::Empty => '.',
+            MazeCell::Start => 'S',
+            MazeCell::End => 'E',
+            MazeCell::Visited => 'o',
+            MazeCell::BestPath => 'O',
+            MazeCell::Reindeer(Direction::Up) => '^',
+            MazeCell::Reindeer(Direction::Right) => '>',
+            MazeCell::Reindeer(Direction::Down) => 'v',
+            MazeCell::Reindeer(Direction::Left) => '<',
+            MazeCell::Reindeer(_) => '*',
+        }
+    }
+
+    fn colour(&self) -> Colour {
+        match self {
+            MazeCell::Wall => Colour::White,
+            MazeCell::Empty => Colour::Grey,
+            MazeCell::Start | MazeCell::End => Colour::Magenta,
+            MazeCell::Visited => Colour::Cyan,
+            MazeCell::BestPath => Colour::Green,
+            MazeCell::Reindeer(_) => Colour::Red,
+        }
+    }
+}
+
+/// The reindeer following one of the best paths, one step or turn per frame, followed by a frame
+/// showing every tile on any of the best paths.
+pub struct BestPaths {
+    maze: Grid<MazeCell>,
+    path: Vec<(Position, Direction)>,
+    best_path_tiles: HashSet<Position>,
+}
+
+impl BestPaths {
+    pub fn new(maze: &Maze) -> Self {
+        let paths = best_paths(maze).into_iter().flatten().collect::<Vec<_>>();
+
+        let best_path_tiles = paths
+            .iter()
+            .flatten()
+            .map(|(position, _direction)| *position)
+            .collect();
+
+        let mut cells = maze.tiles.map(|tile| match tile {
+            Tile::Wall => MazeCell::Wall,
+            Tile::Empty => MazeCell::Empty,
+        });
+        cells[maze.start] = MazeCell::Start;
+        cells[maze.end] = MazeCell::End;
+
+        Self {
+            maze: cells,
+            path: paths.into_iter().next().unwrap_or_default(),
+            best_path_tiles,
+        }
+    }
+
+    fn mark(frame: &mut Grid<MazeCell>, position: Position, cell: MazeCell) {
+        if frame[position] == MazeCell::Empty || matches!(cell, MazeCell::Reindeer(_)) {
+            frame[position] = cell;
+        }
+    }
+}
+
+impl Frames for BestPaths {
+    type Cell = MazeCell;
+
+    fn frame_count(&self) -> usize {
+        self.path.len() + 1
+    }
+
+    fn frame(&mut self, index: usize) -> Grid<Self::Cell> {
+        let mut frame = self.maze.clone();
+
+        if index == self.path.len() {
+            for &position in &self.best_path_tiles {
+                Self::mark(&mut frame, position, MazeCell::BestPath);
+            }
+
+            return frame;
+        }
+
+        for &(position, _direction) in &self.path[..index] {
+            Self::mark(&mut frame, position, MazeCell::Visited);
+        }
+
+        let (position, direction) = self.path[index];
+        Self::mark(&mut frame, position, MazeCell::Reindeer(direction));
+
+        frame
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseInputError {
+    Maze(ParseGridError),
+    Missing(char),
+    Duplicate { character: char, location: Location },
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseInputError::Maze(error) => write!(f, "{}", error),
+            ParseInputError::Missing(character) => {
+                write!(f, "no {:?} tile found in the maze", character)
+            }
+            ParseInputError::Duplicate {
+                character,
+                location,
+            } => write!(f, "{}: found a second {:?} tile", location, character),
+        }
+    }
+}
+
+impl Error for ParseInputError {}
+
+pub fn parse_input(input: &str) -> Result<Maze, ParseInputError> {
+    let characters = Grid::parse_with(input, |character| {
+        MAZE_CHARACTERS.contains(character).then_some(character)
+    })
+    .map_err(ParseInputError::Maze)?;
+
+    let find_single = |character: char| {
+        let mut positions = characters.positions_of(&character);
+        let position = positions
+            .next()
+            .ok_or(ParseInputError::Missing(character))?;
+
+        if let Some(duplicate) = positions.next() {
+            return Err(ParseInputError::Duplicate {
+                character,
+                location: Location::new(duplicate.y + 1, duplicate.x + 1),
+            });
+        }
+
+        Ok(position)
+    };
+
+    let start = find_single('S')?;
+    let end = find_single('E')?;
+
+    let tiles = characters.map(|&character| match character {
+        '#' => Tile::Wall,
+        _ => Tile::Empty,
+    });
+
+    Ok(Maze { start, end, tiles })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT_1: &str = include_str!("aoc-input/example-input-1.txt");
+    const EXAMPLE_INPUT_2: &str = include_str!("aoc-input/example-input-2.txt");
+
+    #[test]
+    fn calculate_lowest_score_works() {
+        assert_eq!(
+            calculate_lowest_score(&parse_input(EXAMPLE_INPUT_1).unwrap()),
+            Some(7036)
+        );
+        assert_eq!(
+            calculate_lowest_score(&parse_input(EXAMPLE_INPUT_2).unwrap()),
+            Some(11048)
+        );
+    }
+
+    #[test]
+    fn best_paths_works() {
+        let mut best_paths = BestPaths::new(&parse_input(EXAMPLE_INPUT_1).unwrap());
+
+        let last_frame = best_paths.frame(best_paths.frame_count() - 1);
+
+        assert_eq!(
+            last_frame
+                .iter()
+                .filter(|(_, cell)| matches!(
+                    cell,
+                    MazeCell::BestPath | MazeCell::Start | MazeCell::End
+                ))
+                .count(),
+            45
+        );
+        assert_eq!(
+            best_paths
+                .frame(0)
+                .position_of(&MazeCell::Reindeer(Direction::Right)),
+            Some(Position::new(1, 13))
+        );
+    }
+
+    #[test]
+    fn count_tiles_on_best_paths_works() {
+        assert_eq!(
+            count_tiles_on_best_paths(&parse_input(EXAMPLE_INPUT_1).unwrap()),
+            Some(45)
+        );
+        assert_eq!(
+            count_tiles_on_best_paths(&parse_input(EXAMPLE_INPUT_2).unwrap()),
+            Some(64)
+        );
+    }
+
+    /// Walks every path from the start that never visits a tile twice, since going round a loop
+    /// can't be cheaper than turning on the spot. Returns the lowest score and how many tiles are
+    /// on a path with that score, or `None` if the end can't be reached.
+    fn naive_best_paths(rows: &[String]) -> Option<(u32, usize)> {
+        let tiles = rows
+            .iter()
+            .map(|row| row.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let find = |target| {
+            (0..tiles.len())
+                .flat_map(|y| (0..tiles[y].len()).map(move |x| (x, y)))
+                .find(|&(x, y)| tiles[y][x] == target)
+                .unwrap()
+        };
+
+        let mut path = vec![find('S')];
+        let mut best = None;
+        walk(&tiles, find('E'), (1, 0), 0, &mut path, &mut best);
+
+        best.map(|(score, tiles): (u32, HashSet<_>)| (score, tiles.len()))
+    }
+
+    fn walk(
+        tiles: &[Vec<char>],
+        end: (usize, usize),
+        heading: (isize, isize),
+        score: u32,
+        path: &mut Vec<(usize, usize)>,
+        best: &mut Option<(u32, HashSet<(usize, usize)>)>,
+    ) {
+        if best
+            .as_ref()
+            .is_some_and(|(best_score, _)| score > *best_score)
+        {
+            return;
+        }
+
+        let (x, y) = *path.last().unwrap();
+
+        if (x, y) == end {
+            match best {
+                Some((best_score, best_tiles)) if *best_score == score => {
+                    best_tiles.extend(path.iter().copied())
+                }
+                _ => *best = Some((score, path.iter().copied().collect())),
+            }
+            return;
+        }
+
+        for step in [(0, -1), (1, 0), (0, 1), (-1, 0)] {
+            let next = (x.wrapping_add_signed(step.0), y.wrapping_add_signed(step.1));
+
+            if tiles[next.1][next.0] == '#' || path.contains(&next) {
+                continue;
+            }
+
+            let turns = match step {
+                _ if step == heading => 0,
+                _ if step == (-heading.0, -heading.1) => 2,
+                _ => 1,
+            };
+
+            path.push(next);
+            walk(tiles, end, step, score + 1 + turns * 1000, path, best);
+            path.pop();
+        }
+    }
+
+    /// A maze of up to 5 by 4 open tiles, walled all the way around.
+    fn maze() -> impl Strategy<Value = Vec<String>> {
+        (2..=5usize, 2..=4usize).prop_flat_map(|(width, height)| {
+            let tiles = width * height;
+
+            (
+                proptest::collection::vec(proptest::bool::weighted(0.25), tiles),
+                0..tiles,
+                0..tiles,
+            )
+                .prop_filter("start and end overlap", |(_, start, end)| start != end)
+                .prop_map(move |(walls, start, end)| {
+                    let wall_row = "#".repeat(width + 2);
+                    let inner_rows = (0..height).map(|y| {
+                        let row = (0..width)
+                            .map(|x| match y * width + x {
+                                index if index == start => 'S',
+                                index if index == end => 'E',
+                                index if walls[index] => '#',
+                                _ => '.',
+                            })
+                            .collect::<String>();
+
+                        format!("#{}#", row)
+                    });
+
+                    std::iter::once(wall_row.clone())
+                        .chain(inner_rows)
+                        .chain(std::iter::once(wall_row))
+                        .collect()
+                })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn best_paths_match_exhaustive_search(rows in maze()) {
+            let naive = naive_best_paths(&rows);
+
+            let input = rows.join("\n");
+            let maze = parse_input(&input).unwrap();
+
+            prop_assert_eq!(
+                calculate_lowest_score(&maze).zip(count_tiles_on_best_paths(&maze)),
+                naive,
+                "maze:\n{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn check_works() {
+        assert!(Day16::check(EXAMPLE_INPUT_1).is_empty());
+        assert!(Day16::check(EXAMPLE_INPUT_2).is_empty());
+        assert_eq!(
+            Day16::check("#####\n#S.S.\n#x.##\n#####"),
+            vec![
+                Violation::at(Location::new(3, 2), "unexpected character 'x'"),
+                Violation::at(
+                    Location::new(2, 5),
+                    "expected a wall '#' on the edge, found '.'"
+                ),
+                Violation::at(Location::new(2, 4), "found a second 'S' tile"),
+                Violation::anywhere("no 'E' tile found in the maze"),
+            ]
+        );
+        assert_eq!(
+            Day16::check(UNREACHABLE_END),
+            vec![Violation::at(
+                Location::new(2, 4),
+                "the 'E' tile can't be reached from the 'S' tile"
+            )]
+        );
+    }
+
+    const UNREACHABLE_END: &str = "#####\n#S#E#\n#####";
+
+    #[test]
+    fn unreachable_end_works() {
+        let maze = parse_input(UNREACHABLE_END).unwrap();
+
+        assert_eq!(calculate_lowest_score(&maze), None);
+        assert_eq!(count_tiles_on_best_paths(&maze), None);
+        assert_eq!(BestPaths::new(&maze).frame_count(), 1);
+        assert_eq!(
+            common::Answer::from(Day16::part_one(&maze)),
+            common::Answer::NotFound
+        );
+    }
+}
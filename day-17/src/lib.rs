@@ -0,0 +1,424 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use common::{Answer, DidNotFinish, Located, Solution};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, line_ending, multispace0},
+    combinator::{cut, eof, map_res, verify},
+    error::ErrorKind,
+    multi::{many_m_n, separated_list1},
+    IResult,
+};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = (Computer, Vec<u8>);
+    type ParseError = Located<ParseInputError>;
+    type PartOne = Result<String, DidNotFinish>;
+    type PartTwo = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        let (computer, program) = match parse_input(input) {
+            Ok((_, parsed_input)) => parsed_input,
+            Err(nom::Err::Error(error) | nom::Err::Failure(error)) => {
+                return Err(Located::at(
+                    input,
+                    error.input,
+                    ParseInputError::Syntax {
+                        kind: error.code,
+                        found: error.input.lines().next().unwrap_or_default().to_string(),
+                    },
+                ))
+            }
+            Err(nom::Err::Incomplete(_)) => {
+                return Err(Located::at(
+                    input,
+                    &input[input.len()..],
+                    ParseInputError::Syntax {
+                        kind: ErrorKind::Eof,
+                        found: String::new(),
+                    },
+                ))
+            }
+        };
+
+        if let Some(index) = find_reserved_combo_operand(&program) {
+            // The program is the last line, so its values are the comma-separated text after the
+            // last label.
+            let operand = input
+                .rfind(PROGRAM_LABEL)
+                .and_then(|start| input[start + PROGRAM_LABEL.len()..].split(',').nth(index))
+                .unwrap_or(&input[input.len()..]);
+
+            return Err(Located::at(
+                input,
+                operand,
+                ParseInputError::ReservedComboOperand {
+                    opcode: program[index - 1],
+                },
+            ));
+        }
+
+        Ok((computer, program))
+    }
+
+    fn part_one((computer, instructions): &Self::Input) -> Self::PartOne {
+        calculate_program_output(computer, instructions, MAX_INSTRUCTIONS)
+    }
+
+    fn part_two(_input: &Self::Input) -> Self::PartTwo {
+        Answer::Unsolved
+    }
+}
+
+/// The most instructions a program may run before it is assumed to be stuck in a loop. Puzzle
+/// programs shift register A right by three bits each time round their one loop, so they finish
+/// within a few hundred.
+pub const MAX_INSTRUCTIONS: usize = 1_000_000;
+
+/// Runs the program until it halts, giving up after `max_instructions` instructions with the
+/// output so far and the state of the computer.
+pub fn calculate_program_output(
+    computer: &Computer,
+    instructions: &[u8],
+    max_instructions: usize,
+) -> Result<String, DidNotFinish> {
+    let mut computer = computer.clone();
+
+    let mut instruction_pointer = 0;
+    let mut instructions_run = 0;
+
+    while let Some([opcode, operand]) = instructions.windows(2).nth(instruction_pointer) {
+        if instructions_run == max_instructions {
+            return Err(DidNotFinish::new(format!(
+                "the program was still running after {} instructions, at instruction {} with \
+                 registers A={}, B={}, C={} and {} values output so far, ending {:?}",
+                max_instructions,
+                instruction_pointer,
+                computer.register_a,
+                computer.register_b,
+                computer.register_c,
+                computer.output.len(),
+                format_output(&computer.output[computer.output.len().saturating_sub(8)..])
+            )));
+        }
+        instructions_run += 1;
+
+        if COMBO_OPCODES.contains(opcode) && *operand == RESERVED_COMBO_OPERAND {
+            return Err(DidNotFinish::new(format!(
+                "instruction {} has the reserved combo operand 7",
+                instruction_pointer
+            )));
+        }
+
+        let initial_instruction_pointer = instruction_pointer;
+
+        match opcode {
+            0 => {
+                adv(&mut computer, operand);
+            }
+            1 => {
+                bxl(&mut computer, operand);
+            }
+            2 => {
+                bst(&mut computer, operand);
+            }
+            3 => {
+                jnz(&computer, &mut instruction_pointer, operand);
+            }
+            4 => {
+                bxc(&mut computer);
+            }
+            5 => {
+                out(&mut computer, operand);
+            }
+            6 => {
+                bdv(&mut computer, operand);
+            }
+            7 => {
+                cdv(&mut computer, operand);
+            }
+            _ => unreachable!("values are 3-bit unsigned integers"),
+        }
+
+        if instruction_pointer == initial_instruction_pointer {
+            instruction_pointer += 2;
+        }
+    }
+
+    Ok(format_output(&computer.output))
+}
+
+fn format_output(output: &[i32]) -> String {
+    output
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn adv(computer: &mut Computer, operand: &u8) {
+    computer.register_a = divide_by_power_of_two(computer.register_a, computer, operand);
+}
+
+fn bxl(computer: &mut Computer, operand: &u8) {
+    computer.register_b ^= *operand as i32;
+}
+
+fn bst(computer: &mut Computer, operand: &u8) {
+    computer.register_b = get_combo_operand(computer, operand) % 8
+}
+
+fn jnz(computer: &Computer, instruction_pointer: &mut usize, operand: &u8) {
+    if computer.register_a == 0 {
+        return;
+    }
+
+    *instruction_pointer = *operand as usize;
+}
+
+fn bxc(computer: &mut Computer) {
+    computer.register_b ^= computer.register_c;
+}
+
+fn out(computer: &mut Computer, operand: &u8) {
+    computer
+        .output
+        .push(get_combo_operand(computer, operand) % 8);
+}
+
+fn bdv(computer: &mut Computer, operand: &u8) {
+    computer.register_b = divide_by_power_of_two(computer.register_a, computer, operand);
+}
+
+fn cdv(computer: &mut Computer, operand: &u8) {
+    computer.register_c = divide_by_power_of_two(computer.register_a, computer, operand);
+}
+
+/// Divides `numerator` by two to the power of the combo operand. Registers never go negative, so
+/// this is a right shift, and shifting by 32 or more leaves nothing.
+fn divide_by_power_of_two(numerator: i32, computer: &Computer, operand: &u8) -> i32 {
+    numerator
+        .checked_shr(get_combo_operand(computer, operand) as u32)
+        .unwrap_or(0)
+}
+
+fn get_combo_operand(computer: &Computer, operand: &u8) -> i32 {
+    match operand {
+        0..=3 => *operand as i32,
+        4 => computer.register_a,
+        5 => computer.register_b,
+        6 => computer.register_c,
+        7 => unreachable!("programs stop before running the reserved combo operand"),
+        _ => unreachable!("values are 3-bit unsigned integers"),
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Computer {
+    register_a: i32,
+    register_b: i32,
+    register_c: i32,
+    output: Vec<i32>,
+}
+
+/// Opcodes of the instructions that take a combo operand: adv, bst, out, bdv and cdv.
+const COMBO_OPCODES: [u8; 5] = [0, 2, 5, 6, 7];
+
+const JNZ_OPCODE: u8 = 3;
+
+const RESERVED_COMBO_OPERAND: u8 = 7;
+
+const PROGRAM_LABEL: &str = "Program: ";
+
+/// Finds the index of a reserved combo operand in any instruction the program could run. Running
+/// carries on two values at a time from index 0 or from wherever a `jnz` lands, so a jump to an
+/// odd index means every odd index could run too.
+fn find_reserved_combo_operand(program: &[u8]) -> Option<usize> {
+    let jumps_to_odd_index = program
+        .windows(2)
+        .any(|pair| pair[0] == JNZ_OPCODE && pair[1] % 2 == 1);
+
+    program
+        .windows(2)
+        .enumerate()
+        .filter(|(index, _)| index % 2 == 0 || jumps_to_odd_index)
+        .find(|(_, pair)| COMBO_OPCODES.contains(&pair[0]) && pair[1] == RESERVED_COMBO_OPERAND)
+        .map(|(index, _)| index + 1)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseInputError {
+    Syntax { kind: ErrorKind, found: String },
+    ReservedComboOperand { opcode: u8 },
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseInputError::Syntax { kind, found } => {
+                let expected = match kind {
+                    ErrorKind::Tag => "a register or program label",
+                    ErrorKind::Digit | ErrorKind::MapRes => "a number",
+                    ErrorKind::Verify => "a 3-bit number",
+                    ErrorKind::CrLf | ErrorKind::ManyMN => "a line break",
+                    ErrorKind::Eof => "the end of the input",
+                    kind => kind.description(),
+                };
+
+                write!(f, "expected {}, found {:?}", expected, found)
+            }
+            ParseInputError::ReservedComboOperand { opcode } => write!(
+                f,
+                "opcode {} takes a combo operand, which cannot be the reserved 7",
+                opcode
+            ),
+        }
+    }
+}
+
+impl Error for ParseInputError {}
+
+fn parse_register<'a>(input: &'a str, register: &char) -> IResult<&'a str, i32> {
+    let (input, _) = tag(format!("Register {}: ", register).as_str())(input)?;
+    let (input, value) = map_res(digit1, |digits: &str| digits.parse::<i32>())(input)?;
+    Ok((input, value))
+}
+
+fn parse_program(input: &str) -> IResult<&str, Vec<u8>> {
+    let (input, _) = tag(PROGRAM_LABEL)(input)?;
+    let (input, value) = separated_list1(
+        tag(","),
+        cut(verify(
+            map_res(digit1, |digits: &str| digits.parse::<u8>()),
+            |value| *value < 8,
+        )),
+    )(input)?;
+    Ok((input, value))
+}
+
+fn parse_input(input: &str) -> IResult<&str, (Computer, Vec<u8>)> {
+    let (input, register_a) = parse_register(input, &'A')?;
+    let (input, _) = line_ending(input)?;
+    let (input, register_b) = parse_register(input, &'B')?;
+    let (input, _) = line_ending(input)?;
+    let (input, register_c) = parse_register(input, &'C')?;
+
+    let (input, _) = many_m_n(2, 2, line_ending)(input)?;
+    let (input, program) = parse_program(input)?;
+    let (input, _) = multispace0(input)?;
+    let (input, _) = eof(input)?;
+
+    Ok((
+        input,
+        (
+            Computer {
+                register_a,
+                register_b,
+                register_c,
+                output: Vec::new(),
+            },
+            program,
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("aoc-input/example-input.txt");
+
+    #[test]
+    fn calculate_program_output_works() {
+        let (_, (computer, instructions)) = parse_input(EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(
+            calculate_program_output(&computer, &instructions, MAX_INSTRUCTIONS),
+            Ok(String::from("4,6,3,5,6,3,5,2,1,0"))
+        )
+    }
+
+    #[test]
+    fn calculate_program_output_gives_up() {
+        let (_, (computer, instructions)) =
+            parse_input("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 5,4,3,0").unwrap();
+
+        assert_eq!(
+            calculate_program_output(&computer, &instructions, 5),
+            Err(DidNotFinish::new(
+                "the program was still running after 5 instructions, at instruction 2 with \
+                 registers A=1, B=0, C=0 and 3 values output so far, ending \"1,1,1\""
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_rejects_malformed_input() {
+        assert_eq!(
+            Day17::parse("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,9,4")
+                .err()
+                .map(|error| error.to_string()),
+            Some(String::from(
+                "line 5, column 12: expected a 3-bit number, found \"9,4\""
+            ))
+        );
+        assert_eq!(
+            Day17::parse("Register A: 729\nRegister C: 0\n")
+                .err()
+                .map(|error| error.location),
+            Some(common::Location::new(2, 1))
+        );
+    }
+
+    #[test]
+    fn parse_rejects_reserved_combo_operand() {
+        assert_eq!(
+            Day17::parse("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,7,3,0")
+                .err()
+                .map(|error| error.to_string()),
+            Some(String::from(
+                "line 5, column 16: opcode 5 takes a combo operand, which cannot be the reserved 7"
+            ))
+        );
+
+        // 7 is a fine literal operand, and an odd index only runs if a jump lands on it.
+        let registers = "Register A: 1\nRegister B: 0\nRegister C: 0\n\n";
+        assert!(Day17::parse(&format!("{}Program: 1,7,5,4", registers)).is_ok());
+        assert!(Day17::parse(&format!("{}Program: 0,1,0,5,7,0", registers)).is_ok());
+        assert!(Day17::parse(&format!("{}Program: 3,3,0,5,7,0", registers)).is_err());
+        assert!(Day17::parse(&format!("{}Program: 3,1,0,5,7,0", registers)).is_err());
+    }
+
+    #[test]
+    fn calculate_program_output_stops_at_reserved_combo_operand() {
+        let (_, (computer, instructions)) =
+            parse_input("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,1,0,5,7,0")
+                .unwrap();
+
+        assert_eq!(
+            calculate_program_output(&computer, &instructions, MAX_INSTRUCTIONS),
+            Err(DidNotFinish::new(
+                "instruction 3 has the reserved combo operand 7"
+            ))
+        );
+    }
+
+    #[test]
+    fn calculate_program_output_handles_large_shifts() {
+        let (_, (computer, instructions)) =
+            parse_input("Register A: 2024\nRegister B: 0\nRegister C: 40\n\nProgram: 0,6,5,4")
+                .unwrap();
+
+        assert_eq!(
+            calculate_program_output(&computer, &instructions, MAX_INSTRUCTIONS),
+            Ok(String::from("0"))
+        );
+    }
+}
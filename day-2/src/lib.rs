@@ -0,0 +1,225 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io::BufRead,
+};
+
+use common::{
+    check::check_lines,
+    input::{self, StreamError},
+    Located, Solution, StreamingSolution, Violation,
+};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<usize>>;
+    type ParseError = Located<ParseInputError>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        parse_input(input)
+    }
+
+    fn check(input: &str) -> Vec<Violation> {
+        check_lines(input, |line| parse_line(input, line))
+    }
+
+    fn part_one(reports: &Self::Input) -> Self::PartOne {
+        count_safe_reports(reports)
+    }
+
+    fn part_two(reports: &Self::Input) -> Self::PartTwo {
+        count_safe_reports_with_tolerance(reports)
+    }
+}
+
+impl StreamingSolution for Day2 {
+    type RecordError = ParseInputError;
+
+    fn solve_reader(
+        reader: impl BufRead,
+    ) -> Result<(usize, usize), StreamError<Self::RecordError>> {
+        let mut safe_reports = 0;
+        let mut safe_reports_with_tolerance = 0;
+
+        input::for_each_line(reader, |line| {
+            let report = parse_line(line, line)?;
+
+            safe_reports += usize::from(is_valid_report(&report));
+            safe_reports_with_tolerance += usize::from(is_valid_report_with_tolerance(&report));
+
+            Ok(())
+        })?;
+
+        Ok((safe_reports, safe_reports_with_tolerance))
+    }
+}
+
+pub fn count_safe_reports(reports: &[Vec<usize>]) -> usize {
+    reports
+        .iter()
+        .filter(|report| is_valid_report(report.as_slice()))
+        .count()
+}
+
+pub fn count_safe_reports_with_tolerance(reports: &[Vec<usize>]) -> usize {
+    reports
+        .iter()
+        .filter(|report| is_valid_report_with_tolerance(report))
+        .count()
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseInputError {
+    TooFewLevels(String),
+    InvalidLevel(String),
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseInputError::TooFewLevels(line) => {
+                write!(
+                    f,
+                    "expected a report with at least two levels, found {:?}",
+                    line
+                )
+            }
+            ParseInputError::InvalidLevel(text) => write!(f, "invalid level {:?}", text),
+        }
+    }
+}
+
+impl Error for ParseInputError {}
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<usize>>, Located<ParseInputError>> {
+    input.lines().map(|line| parse_line(input, line)).collect()
+}
+
+fn parse_line(input: &str, line: &str) -> Result<Vec<usize>, Located<ParseInputError>> {
+    let report = line
+        .split_ascii_whitespace()
+        .map(|number| {
+            number.parse::<usize>().map_err(|_| {
+                Located::at(
+                    input,
+                    number,
+                    ParseInputError::InvalidLevel(number.to_string()),
+                )
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if report.len() < 2 {
+        return Err(Located::at(
+            input,
+            line,
+            ParseInputError::TooFewLevels(line.to_string()),
+        ));
+    }
+
+    Ok(report)
+}
+
+/// Whether the report is valid as it is, or once any one of its levels is removed.
+pub fn is_valid_report_with_tolerance(report: &[usize]) -> bool {
+    if is_valid_report(report) {
+        return true;
+    }
+
+    for index in 0..report.len() {
+        let modified_report = [&report[..index], &report[index + 1..]].concat();
+
+        if is_valid_report(modified_report.as_slice()) {
+            return true;
+        }
+    }
+
+    false
+}
+
+pub fn is_valid_report(report: &[usize]) -> bool {
+    let mut windowed_report = report.windows(2);
+
+    let [first, second] = windowed_report.next().unwrap() else {
+        panic!("windows should be 2 items long");
+    };
+
+    match first.abs_diff(*second) {
+        1..=3 => {}
+        _ => return false,
+    }
+
+    let is_increasing = second > first;
+
+    while let Some([first, second]) = windowed_report.next() {
+        if first == second {
+            return false;
+        }
+
+        if (is_increasing && second <= first) || (!is_increasing && second >= first) {
+            return false;
+        }
+
+        match first.abs_diff(*second) {
+            1..=3 => {}
+            _ => return false,
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use common::Location;
+
+    const EXAMPLE_INPUT: &str = include_str!("aoc-input/example-input.txt");
+
+    #[test]
+    fn count_safe_reports_works() {
+        assert_eq!(count_safe_reports(&parse_input(EXAMPLE_INPUT).unwrap()), 2)
+    }
+
+    #[test]
+    fn count_safe_reports_with_tolerance_works() {
+        assert_eq!(
+            count_safe_reports_with_tolerance(&parse_input(EXAMPLE_INPUT).unwrap()),
+            4
+        )
+    }
+
+    #[test]
+    fn solve_reader_works() {
+        assert_eq!(
+            Day2::solve_reader(EXAMPLE_INPUT.as_bytes()).unwrap(),
+            (2, 4)
+        );
+
+        let repeated = EXAMPLE_INPUT.repeat(1000);
+        assert_eq!(
+            Day2::solve_reader(repeated.as_bytes()).unwrap(),
+            (2 * 1000, 4 * 1000)
+        );
+    }
+
+    #[test]
+    fn check_works() {
+        assert!(Day2::check(EXAMPLE_INPUT).is_empty());
+        assert_eq!(
+            Day2::check("1 2 3\n4\n5 six 7\n"),
+            vec![
+                Violation::at(
+                    Location::new(2, 1),
+                    "expected a report with at least two levels, found \"4\""
+                ),
+                Violation::at(Location::new(3, 3), "invalid level \"six\""),
+            ]
+        );
+    }
+}
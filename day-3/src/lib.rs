@@ -0,0 +1,298 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use common::{Located, Solution};
+use lazy_static::lazy_static;
+use regex::{Match, Regex};
+
+const MULTIPLY_REGEX_PATTERN: &str = r"mul\(\d{1,3},\d{1,3}\)";
+
+const DO_DONT_REGEX_PATTERN: &str = r"do\(\)|don't\(\)";
+
+lazy_static! {
+    static ref MULTIPLY_REGEX: Regex =
+        Regex::new(MULTIPLY_REGEX_PATTERN).expect("regex should compile");
+    static ref DO_DONT_REGEX: Regex =
+        Regex::new(DO_DONT_REGEX_PATTERN).expect("regex should compile");
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = CorruptedMemory;
+    type ParseError = Located<ParseInputError>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(corrupted_memory: &Self::Input) -> Self::PartOne {
+        sum_of_multiplication_results(corrupted_memory)
+    }
+
+    fn part_two(corrupted_memory: &Self::Input) -> Self::PartTwo {
+        sum_of_enabled_multiplication_results(corrupted_memory)
+    }
+}
+
+pub struct CorruptedMemory {
+    multiplications: Vec<(usize, Multiplication)>,
+    instructions: Vec<Instruction>,
+}
+
+struct Multiplication(usize, usize);
+
+impl Multiplication {
+    fn result(&self) -> usize {
+        self.0 * self.1
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseMultiplicationError {
+    Malformed(String),
+    InvalidFactor(String),
+}
+
+impl Display for ParseMultiplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseMultiplicationError::Malformed(text) => {
+                write!(f, "expected mul(X,Y), found {:?}", text)
+            }
+            ParseMultiplicationError::InvalidFactor(text) => {
+                write!(f, "invalid multiplication factor {:?}", text)
+            }
+        }
+    }
+}
+
+impl Error for ParseMultiplicationError {}
+
+impl FromStr for Multiplication {
+    type Err = ParseMultiplicationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (first_string, second_string) = s
+            .strip_prefix("mul(")
+            .and_then(|s| s.strip_suffix(')'))
+            .and_then(|s| s.split_once(','))
+            .ok_or_else(|| ParseMultiplicationError::Malformed(s.to_string()))?;
+
+        let parse_factor = |factor: &str| {
+            factor
+                .parse()
+                .map_err(|_| ParseMultiplicationError::InvalidFactor(factor.to_string()))
+        };
+
+        Ok(Self(
+            parse_factor(first_string)?,
+            parse_factor(second_string)?,
+        ))
+    }
+}
+
+#[derive(Clone)]
+struct Instruction {
+    pub do_dont: DoDont,
+    pub start: usize,
+}
+
+impl Instruction {
+    fn new(do_dont: DoDont, start: usize) -> Self {
+        Self { do_dont, start }
+    }
+
+    fn from_match(m: Match) -> Result<Self, ParseDoDontError> {
+        Ok(Self {
+            do_dont: DoDont::from_str(m.as_str())?,
+            start: m.start(),
+        })
+    }
+}
+
+impl Default for Instruction {
+    fn default() -> Self {
+        Self {
+            do_dont: DoDont::Do,
+            start: 0,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+enum DoDont {
+    Do,
+    Dont,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParseDoDontError(String);
+
+impl Display for ParseDoDontError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected do() or don't(), found {:?}", self.0)
+    }
+}
+
+impl Error for ParseDoDontError {}
+
+impl FromStr for DoDont {
+    type Err = ParseDoDontError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "do()" => Ok(Self::Do),
+            "don't()" => Ok(Self::Dont),
+            _ => Err(ParseDoDontError(s.to_string())),
+        }
+    }
+}
+
+pub fn sum_of_multiplication_results(corrupted_memory: &CorruptedMemory) -> usize {
+    corrupted_memory
+        .multiplications
+        .iter()
+        .map(|(_, multiplication)| multiplication.result())
+        .sum()
+}
+
+pub fn sum_of_enabled_multiplication_results(corrupted_memory: &CorruptedMemory) -> usize {
+    let mut instruction_iterator = corrupted_memory.instructions.iter().cloned();
+
+    let mut current_instruction = Instruction::default();
+    let mut next_instruction = instruction_iterator
+        .next()
+        .unwrap_or(Instruction::new(DoDont::Do, usize::MAX));
+
+    corrupted_memory
+        .multiplications
+        .iter()
+        .filter_map(|(multiplication_start, multiplication)| {
+            loop {
+                // The default instruction starts at 0, where a multiplication can start too.
+                if *multiplication_start >= current_instruction.start
+                    && *multiplication_start < next_instruction.start
+                {
+                    break;
+                }
+
+                current_instruction = next_instruction.clone();
+                next_instruction = instruction_iterator
+                    .next()
+                    .unwrap_or(Instruction::new(DoDont::Do, usize::MAX));
+            }
+
+            if current_instruction.do_dont == DoDont::Dont {
+                return None;
+            }
+
+            Some(multiplication.result())
+        })
+        .sum()
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseInputError {
+    Multiplication(ParseMultiplicationError),
+    DoDont(ParseDoDontError),
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseInputError::Multiplication(error) => write!(f, "{}", error),
+            ParseInputError::DoDont(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for ParseInputError {}
+
+pub fn parse_input(input: &str) -> Result<CorruptedMemory, Located<ParseInputError>> {
+    let multiplications = MULTIPLY_REGEX
+        .find_iter(input)
+        .map(|m| {
+            let multiplication = Multiplication::from_str(m.as_str()).map_err(|error| {
+                Located::at(input, m.as_str(), ParseInputError::Multiplication(error))
+            })?;
+
+            Ok((m.start(), multiplication))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let instructions = DO_DONT_REGEX
+        .find_iter(input)
+        .map(|m| {
+            Instruction::from_match(m)
+                .map_err(|error| Located::at(input, m.as_str(), ParseInputError::DoDont(error)))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(CorruptedMemory {
+        multiplications,
+        instructions,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_INPUT_1: &str = include_str!("aoc-input/example-input-1.txt");
+
+    #[test]
+    fn sum_of_multiplication_results_works() {
+        assert_eq!(
+            sum_of_multiplication_results(&parse_input(EXAMPLE_INPUT_1).unwrap()),
+            161
+        );
+    }
+
+    const EXAMPLE_INPUT_2: &str = include_str!("aoc-input/example-input-2.txt");
+
+    #[test]
+    fn sum_of_enabled_multiplication_results_works() {
+        assert_eq!(
+            sum_of_enabled_multiplication_results(&parse_input(EXAMPLE_INPUT_2).unwrap()),
+            48
+        );
+    }
+
+    #[test]
+    fn sum_of_enabled_multiplication_results_works_from_the_start() {
+        assert_eq!(
+            sum_of_enabled_multiplication_results(&parse_input("mul(1,1)").unwrap()),
+            1
+        );
+        assert_eq!(
+            sum_of_enabled_multiplication_results(
+                &parse_input("mul(2,3)don't()mul(4,5)do()mul(1,1)").unwrap()
+            ),
+            7
+        );
+    }
+
+    #[test]
+    fn from_str_rejects_malformed_instructions() {
+        assert_eq!(
+            Multiplication::from_str("mul(2,x)").err(),
+            Some(ParseMultiplicationError::InvalidFactor(String::from("x")))
+        );
+        assert_eq!(
+            Multiplication::from_str("mul").err(),
+            Some(ParseMultiplicationError::Malformed(String::from("mul")))
+        );
+        assert_eq!(
+            DoDont::from_str("do").err(),
+            Some(ParseDoDontError(String::from("do")))
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use std::str::FromStr;
+
+use common::{Solution, Violation};
+use geometry::{Direction, Position};
+use grid::{check_grid, Grid, ParseGridError};
+
+const TARGET_STRING: &str = "XMAS";
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+    type ParseError = ParseGridError;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        Grid::from_str(input)
+    }
+
+    fn check(input: &str) -> Vec<Violation> {
+        check_grid(input, |character| TARGET_STRING.contains(character))
+            .into_iter()
+            .map(Violation::from)
+            .collect()
+    }
+
+    fn part_one(grid: &Self::Input) -> Self::PartOne {
+        count_xmas_occurrences(grid)
+    }
+
+    fn part_two(grid: &Self::Input) -> Self::PartTwo {
+        count_x_mas_occurrences(grid)
+    }
+}
+
+pub fn count_xmas_occurrences(grid: &Grid<char>) -> usize {
+    grid.positions_of(&'X')
+        .map(|position| check_surrounding_characters_for_xmas_occurrences(grid, position))
+        .sum()
+}
+
+pub fn count_x_mas_occurrences(grid: &Grid<char>) -> usize {
+    grid.positions_of(&'A')
+        .filter(|&position| is_center_of_x_mas_occurrence(grid, position))
+        .count()
+}
+
+fn check_surrounding_characters_for_xmas_occurrences(
+    grid: &Grid<char>,
+    position: Position,
+) -> usize {
+    Direction::all()
+        .into_iter()
+        .filter(|&direction| {
+            grid.ray(position, direction)
+                .take(TARGET_STRING.len())
+                .map(|position| grid[position])
+                .eq(TARGET_STRING.chars())
+        })
+        .count()
+}
+
+fn is_center_of_x_mas_occurrence(grid: &Grid<char>, position: Position) -> bool {
+    let corner = |direction| {
+        position
+            .translate(direction)
+            .and_then(|corner| grid.get(corner))
+            .copied()
+    };
+
+    let (Some(top_left), Some(top_right), Some(bottom_right), Some(bottom_left)) = (
+        corner(Direction::UpLeft),
+        corner(Direction::UpRight),
+        corner(Direction::DownRight),
+        corner(Direction::DownLeft),
+    ) else {
+        return false;
+    };
+
+    let top_left_bottom_right_set = [top_left, bottom_right];
+    let top_right_bottom_left_set = [top_right, bottom_left];
+
+    if top_left_bottom_right_set.contains(&'M')
+        && top_left_bottom_right_set.contains(&'S')
+        && top_right_bottom_left_set.contains(&'M')
+        && top_right_bottom_left_set.contains(&'S')
+    {
+        return true;
+    };
+
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use common::Location;
+
+    const EXAMPLE_INPUT: &str = include_str!("aoc-input/example-input.txt");
+
+    #[test]
+    fn count_xmas_occurrences_works() {
+        assert_eq!(
+            count_xmas_occurrences(&Grid::from_str(EXAMPLE_INPUT).unwrap()),
+            18
+        );
+    }
+
+    #[test]
+    fn count_x_mas_occurrences_works() {
+        assert_eq!(
+            count_x_mas_occurrences(&Grid::from_str(EXAMPLE_INPUT).unwrap()),
+            9
+        );
+    }
+
+    #[test]
+    fn check_works() {
+        assert!(Day4::check(EXAMPLE_INPUT).is_empty());
+        assert_eq!(
+            Day4::check("XMAS\nXMAZ\nXM"),
+            vec![
+                Violation::at(Location::new(2, 4), "unexpected character 'Z'"),
+                Violation::at(Location::new(3, 1), "line is 2 characters long, expected 4"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,490 @@
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use common::{Located, Location, Solution, Violation};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = (PageOrderingRules, PagesToProduce);
+    type ParseError = Located<ParseInputError>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        parse_input(input)
+    }
+
+    fn check(input: &str) -> Vec<Violation> {
+        let lines = input.lines().collect::<Vec<_>>();
+
+        let mut sections = lines.splitn(2, |l| l.is_empty());
+        let page_ordering_rules = sections.next().unwrap_or_default();
+        let updates = sections.next();
+
+        let mut violations = page_ordering_rules
+            .iter()
+            .filter_map(|l| parse_page_ordering_rule(input, l).err())
+            .map(Violation::from)
+            .collect::<Vec<_>>();
+
+        let Some(updates) = updates else {
+            violations.push(Violation::from(missing_updates(input)));
+            return violations;
+        };
+
+        for l in updates {
+            match parse_update_pages_to_produce(input, l) {
+                Err(error) => violations.push(Violation::from(error)),
+                Ok(update) if update.0.len() % 2 == 0 => violations.push(Violation::at(
+                    Location::of(input, l),
+                    format!(
+                        "update has an even number of pages, {}, so no middle page",
+                        update.0.len()
+                    ),
+                )),
+                Ok(_) => {}
+            }
+        }
+
+        violations
+    }
+
+    fn part_one((page_ordering_rules, pages_to_produce): &Self::Input) -> Self::PartOne {
+        sum_of_middle_page_numbers_from_correctly_ordered_updates(
+            page_ordering_rules,
+            pages_to_produce,
+        )
+    }
+
+    fn part_two((page_ordering_rules, pages_to_produce): &Self::Input) -> Self::PartTwo {
+        sum_of_middle_page_numbers_from_incorrectly_ordered_updates(
+            page_ordering_rules,
+            pages_to_produce,
+        )
+    }
+}
+
+pub type PageOrderingRules = Vec<PageOrderingRule>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PageOrderingRule(usize, usize);
+
+#[derive(Debug, PartialEq)]
+pub enum ParsePageOrderingRuleError {
+    MissingSeparator(String),
+    InvalidPage(String),
+}
+
+impl Display for ParsePageOrderingRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePageOrderingRuleError::MissingSeparator(text) => {
+                write!(
+                    f,
+                    "expected a page ordering rule like 47|53, found {:?}",
+                    text
+                )
+            }
+            ParsePageOrderingRuleError::InvalidPage(text) => write!(f, "invalid page {:?}", text),
+        }
+    }
+}
+
+impl Error for ParsePageOrderingRuleError {}
+
+impl FromStr for PageOrderingRule {
+    type Err = ParsePageOrderingRuleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (a, b) = s
+            .split_once('|')
+            .ok_or_else(|| ParsePageOrderingRuleError::MissingSeparator(s.to_string()))?;
+
+        let parse_page = |page: &str| {
+            page.parse()
+                .map_err(|_| ParsePageOrderingRuleError::InvalidPage(page.to_string()))
+        };
+
+        Ok(Self(parse_page(a)?, parse_page(b)?))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UpdatePagesToProduce(Vec<usize>);
+
+#[derive(Debug, PartialEq)]
+pub struct ParseUpdatePagesToProduceError(String);
+
+impl Display for ParseUpdatePagesToProduceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid page {:?}", self.0)
+    }
+}
+
+impl Error for ParseUpdatePagesToProduceError {}
+
+impl FromStr for UpdatePagesToProduce {
+    type Err = ParseUpdatePagesToProduceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(UpdatePagesToProduce(
+            s.split(',')
+                .map(|x| {
+                    x.parse::<usize>()
+                        .map_err(|_| ParseUpdatePagesToProduceError(x.to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        ))
+    }
+}
+
+pub type PagesToProduce = Vec<UpdatePagesToProduce>;
+
+pub fn sum_of_middle_page_numbers_from_correctly_ordered_updates(
+    page_ordering_rules: &PageOrderingRules,
+    pages_to_produce: &PagesToProduce,
+) -> usize {
+    pages_to_produce
+        .iter()
+        .filter(|update_pages_to_produce| {
+            is_update_pages_to_produce_valid(update_pages_to_produce, page_ordering_rules)
+        })
+        .map(|update_pages_to_produce| {
+            let pages = &update_pages_to_produce.0;
+            pages[pages.len() / 2]
+        })
+        .sum()
+}
+
+pub fn sum_of_middle_page_numbers_from_incorrectly_ordered_updates(
+    page_ordering_rules: &PageOrderingRules,
+    pages_to_produce: &PagesToProduce,
+) -> usize {
+    pages_to_produce
+        .iter()
+        .filter(|update_pages_to_produce| {
+            !is_update_pages_to_produce_valid(update_pages_to_produce, page_ordering_rules)
+        })
+        .map(|update_pages_to_produce| {
+            reorder_update_pages_to_produce(update_pages_to_produce, page_ordering_rules)
+        })
+        .map(|update_pages_to_produce| {
+            let pages = &update_pages_to_produce.0;
+            pages[pages.len() / 2]
+        })
+        .sum()
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseInputError {
+    MissingUpdates,
+    PageOrderingRule(ParsePageOrderingRuleError),
+    UpdatePagesToProduce(ParseUpdatePagesToProduceError),
+}
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseInputError::MissingUpdates => write!(
+                f,
+                "expected a blank line between the page ordering rules and the updates"
+            ),
+            ParseInputError::PageOrderingRule(error) => write!(f, "{}", error),
+            ParseInputError::UpdatePagesToProduce(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for ParseInputError {}
+
+pub fn parse_input(
+    input: &str,
+) -> Result<(PageOrderingRules, PagesToProduce), Located<ParseInputError>> {
+    let lines = input.lines().collect::<Vec<_>>();
+
+    let mut result = lines.splitn(2, |l| l.is_empty());
+    let a = result.next().unwrap_or_default();
+    let b = result.next().ok_or_else(|| missing_updates(input))?;
+
+    let page_ordering_rules = a
+        .iter()
+        .map(|l| parse_page_ordering_rule(input, l))
+        .collect::<Result<Vec<_>, _>>()?;
+    let pages_to_produce = b
+        .iter()
+        .map(|l| parse_update_pages_to_produce(input, l))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((page_ordering_rules, pages_to_produce))
+}
+
+fn missing_updates(input: &str) -> Located<ParseInputError> {
+    Located::at(
+        input,
+        &input[input.len()..],
+        ParseInputError::MissingUpdates,
+    )
+}
+
+fn parse_page_ordering_rule(
+    input: &str,
+    line: &str,
+) -> Result<PageOrderingRule, Located<ParseInputError>> {
+    PageOrderingRule::from_str(line)
+        .map_err(|error| Located::at(input, line, ParseInputError::PageOrderingRule(error)))
+}
+
+fn parse_update_pages_to_produce(
+    input: &str,
+    line: &str,
+) -> Result<UpdatePagesToProduce, Located<ParseInputError>> {
+    UpdatePagesToProduce::from_str(line)
+        .map_err(|error| Located::at(input, line, ParseInputError::UpdatePagesToProduce(error)))
+}
+
+pub fn is_update_pages_to_produce_valid(
+    update_pages_to_produce: &UpdatePagesToProduce,
+    page_ordering_rules: &PageOrderingRules,
+) -> bool {
+    let pages = update_pages_to_produce.0.clone();
+    for page in pages.as_slice() {
+        for later_page in pages.iter().skip_while(|&x| x != page) {
+            for rule in page_ordering_rules {
+                if &rule.0 == page && &rule.1 == later_page {
+                    break;
+                } else if &rule.0 == later_page && &rule.1 == page {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+pub fn reorder_update_pages_to_produce(
+    update_pages_to_produce: &UpdatePagesToProduce,
+    page_ordering_rules: &PageOrderingRules,
+) -> UpdatePagesToProduce {
+    let rules = page_ordering_rules.as_slice();
+
+    let mut result = update_pages_to_produce.0.clone().clone();
+
+    result.sort_unstable_by(|a, b| {
+        match rules
+            .iter()
+            .find(|rule| (&rule.0 == a && &rule.1 == b) || (&rule.0 == b && &rule.1 == a))
+        {
+            Some(rule) => {
+                if &rule.0 == a {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                }
+            }
+            None => Ordering::Equal,
+        }
+    });
+    UpdatePagesToProduce(result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("aoc-input/example-input.txt");
+
+    #[test]
+    fn sum_of_middle_page_numbers_from_correctly_ordered_updates_works() {
+        let (page_ordering_rules, pages_to_produce) = parse_input(EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(
+            sum_of_middle_page_numbers_from_correctly_ordered_updates(
+                &page_ordering_rules,
+                &pages_to_produce
+            ),
+            143
+        );
+    }
+
+    #[test]
+    fn sum_of_middle_page_numbers_from_incorrectly_ordered_updates_works() {
+        let (page_ordering_rules, pages_to_produce) = parse_input(EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(
+            sum_of_middle_page_numbers_from_incorrectly_ordered_updates(
+                &page_ordering_rules,
+                &pages_to_produce
+            ),
+            123
+        );
+    }
+
+    #[test]
+    fn parse_input_works() {
+        let (page_ordering_rules, pages_to_produce) = parse_input(EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(
+            page_ordering_rules,
+            vec![
+                PageOrderingRule(47, 53),
+                PageOrderingRule(97, 13),
+                PageOrderingRule(97, 61),
+                PageOrderingRule(97, 47),
+                PageOrderingRule(75, 29),
+                PageOrderingRule(61, 13),
+                PageOrderingRule(75, 53),
+                PageOrderingRule(29, 13),
+                PageOrderingRule(97, 29),
+                PageOrderingRule(53, 29),
+                PageOrderingRule(61, 53),
+                PageOrderingRule(97, 53),
+                PageOrderingRule(61, 29),
+                PageOrderingRule(47, 13),
+                PageOrderingRule(75, 47),
+                PageOrderingRule(97, 75),
+                PageOrderingRule(47, 61),
+                PageOrderingRule(75, 61),
+                PageOrderingRule(47, 29),
+                PageOrderingRule(75, 13),
+                PageOrderingRule(53, 13),
+            ]
+        );
+
+        assert_eq!(
+            pages_to_produce,
+            vec![
+                UpdatePagesToProduce(vec![75, 47, 61, 53, 29]),
+                UpdatePagesToProduce(vec![97, 61, 53, 29, 13]),
+                UpdatePagesToProduce(vec![75, 29, 13]),
+                UpdatePagesToProduce(vec![75, 97, 47, 61, 53]),
+                UpdatePagesToProduce(vec![61, 13, 29]),
+                UpdatePagesToProduce(vec![97, 13, 75, 29, 47]),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_input_rejects_malformed_input() {
+        assert_eq!(
+            parse_input("47|53\n97-13\n\n75,47\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: expected a page ordering rule like 47|53, found \"97-13\""
+        );
+        assert_eq!(
+            parse_input("47|53\n\n75,,47\n").unwrap_err().to_string(),
+            "line 3, column 1: invalid page \"\""
+        );
+        assert_eq!(
+            parse_input("47|53\n97|13\n").unwrap_err().to_string(),
+            "line 3, column 1: expected a blank line between the page ordering rules and the updates"
+        );
+    }
+
+    #[test]
+    fn reorder_update_pages_to_produce_works() {
+        let (page_ordering_rules, _) = parse_input(EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(
+            reorder_update_pages_to_produce(
+                &UpdatePagesToProduce(vec![75, 97, 47, 61, 53]),
+                &page_ordering_rules
+            ),
+            UpdatePagesToProduce(vec![97, 75, 47, 61, 53])
+        );
+
+        assert_eq!(
+            reorder_update_pages_to_produce(
+                &UpdatePagesToProduce(vec![61, 13, 29]),
+                &page_ordering_rules
+            ),
+            UpdatePagesToProduce(vec![61, 29, 13])
+        );
+
+        assert_eq!(
+            reorder_update_pages_to_produce(
+                &UpdatePagesToProduce(vec![97, 13, 75, 29, 47]),
+                &page_ordering_rules
+            ),
+            UpdatePagesToProduce(vec![97, 75, 47, 29, 13])
+        );
+    }
+
+    /// Rules ordering every pair of pages, and an update of some of those pages in any order.
+    fn rules_and_update() -> impl Strategy<Value = (PageOrderingRules, UpdatePagesToProduce)> {
+        (
+            Just((10..60).collect::<Vec<usize>>()).prop_shuffle(),
+            1..20usize,
+        )
+            .prop_flat_map(|(order, update_len)| {
+                let rules = order
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, &before)| {
+                        order[i + 1..]
+                            .iter()
+                            .map(move |&after| PageOrderingRule(before, after))
+                    })
+                    .collect::<Vec<_>>();
+
+                (
+                    Just(rules).prop_shuffle(),
+                    Just(order[..update_len].to_vec())
+                        .prop_shuffle()
+                        .prop_map(UpdatePagesToProduce),
+                )
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn reorder_update_pages_to_produce_returns_valid_update(
+            (page_ordering_rules, update) in rules_and_update()
+        ) {
+            let reordered = reorder_update_pages_to_produce(&update, &page_ordering_rules);
+
+            prop_assert!(is_update_pages_to_produce_valid(&reordered, &page_ordering_rules));
+
+            let mut pages = update.0.clone();
+            let mut reordered_pages = reordered.0.clone();
+            pages.sort();
+            reordered_pages.sort();
+            prop_assert_eq!(pages, reordered_pages);
+        }
+    }
+
+    #[test]
+    fn check_works() {
+        assert!(Day5::check(EXAMPLE_INPUT).is_empty());
+        assert_eq!(
+            Day5::check("47|53\n97-13\n47|x\n\n75,47\n75,,47\n75,47,53\n"),
+            vec![
+                Violation::at(
+                    Location::new(2, 1),
+                    "expected a page ordering rule like 47|53, found \"97-13\""
+                ),
+                Violation::at(Location::new(3, 1), "invalid page \"x\""),
+                Violation::at(
+                    Location::new(5, 1),
+                    "update has an even number of pages, 2, so no middle page"
+                ),
+                Violation::at(Location::new(6, 1), "invalid page \"\""),
+            ]
+        );
+        assert_eq!(
+            Day5::check("47|53\n97|13\n"),
+            vec![Violation::at(
+                Location::new(3, 1),
+                "expected a blank line between the page ordering rules and the updates"
+            )]
+        );
+    }
+}
@@ -0,0 +1,563 @@
+use std::{collections::HashSet, str::FromStr};
+
+use common::{pool::Pool, DidNotFinish, Location, Solution, Violation};
+use geometry::{Direction, Position};
+use grid::{check_grid, Grid, ParseGridError};
+pub use lab_map::{LabMap, ParseLabMapError, StepForwardError, Tile};
+use visualizer::{Cell, Colour, Frames};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = LabMap;
+    type ParseError = ParseLabMapError;
+    type PartOne = Result<usize, DidNotFinish>;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        LabMap::from_str(input)
+    }
+
+    fn check(input: &str) -> Vec<Violation> {
+        let mut violations = Vec::new();
+
+        for error in check_grid(input, |character| matches!(character, '.' | '#' | '^')) {
+            match error {
+                ParseGridError::InvalidCharacter {
+                    line,
+                    column,
+                    character: character @ ('>' | 'v' | '<'),
+                } => violations.push(Violation::at(
+                    Location::new(line, column),
+                    format!(
+                        "guard {:?} is not facing up, only '^' is supported",
+                        character
+                    ),
+                )),
+                error => violations.push(Violation::from(error)),
+            }
+        }
+
+        let mut guard_locations = input.lines().enumerate().flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|&(_, character)| character == '^')
+                .map(move |(x, _)| Location::new(y + 1, x + 1))
+        });
+
+        if guard_locations.next().is_none() {
+            violations.push(Violation::anywhere(ParseLabMapError::MissingGuard));
+        }
+
+        violations.extend(
+            guard_locations.map(|location| Violation::at(location, "found a second guard '^'")),
+        );
+
+        violations
+    }
+
+    fn part_one(lab_map: &Self::Input) -> Self::PartOne {
+        distinct_guard_visit_positions(lab_map, max_steps(lab_map))
+    }
+
+    fn part_two(lab_map: &Self::Input) -> Self::PartTwo {
+        add_obstruction_potential_positions(lab_map)
+    }
+}
+
+/// The most steps a guard who leaves the mapped area can take: one for each position and
+/// direction, as a guard who walks any of them twice is stuck in a loop.
+pub fn max_steps(lab_map: &LabMap) -> usize {
+    4 * lab_map.width() * lab_map.height()
+}
+
+/// Counts the positions the guard visits before leaving the mapped area, giving up after
+/// `max_steps` steps, or once the guard is boxed in, with the positions visited so far.
+pub fn distinct_guard_visit_positions(
+    lab_map: &LabMap,
+    max_steps: usize,
+) -> Result<usize, DidNotFinish> {
+    let mut lab_map = lab_map.clone();
+
+    let mut visited_positions = HashSet::new();
+
+    for _ in 0..max_steps {
+        let current_guard_position = *lab_map.current_guard_position();
+        visited_positions.insert(current_guard_position);
+
+        if !lab_map.turn_until_unobstructed() {
+            return Err(DidNotFinish::new(format!(
+                "the guard is boxed in at {},{}",
+                current_guard_position.x, current_guard_position.y
+            )));
+        }
+
+        match lab_map.step_forward() {
+            Ok(_) => {}
+            Err(StepForwardError::LeftMappedArea) => return Ok(visited_positions.len()),
+            Err(StepForwardError::Obstruction) => panic!("Obstructions should not be hit"),
+        }
+    }
+
+    let position = lab_map.current_guard_position();
+    visited_positions.insert(*position);
+
+    Err(DidNotFinish::new(format!(
+        "the guard is still walking after {} steps, likely in a loop, having visited {} \
+         positions and now at {},{} facing {:?}",
+        max_steps,
+        visited_positions.len(),
+        position.x,
+        position.y,
+        lab_map.current_guard_direction()
+    )))
+}
+
+pub fn add_obstruction_potential_positions(lab_map: &LabMap) -> usize {
+    let positions = (0..lab_map.height())
+        .flat_map(|y| (0..lab_map.width()).map(move |x| Position::new(x, y)))
+        .collect::<Vec<_>>();
+
+    Pool::default()
+        .map_reduce_with(
+            &positions,
+            || lab_map.clone(),
+            |lab_map, position| usize::from(does_obstruction_cause_loop(lab_map, position)),
+            |a, b| a + b,
+        )
+        .unwrap_or(0)
+}
+
+/// Whether an obstruction at `position` traps the guard in a loop. `lab_map` is reset before use,
+/// so it can be reused between positions.
+fn does_obstruction_cause_loop(lab_map: &mut LabMap, position: &Position) -> bool {
+    lab_map.reset();
+
+    if lab_map.is_position_obstructed(position) || lab_map.current_guard_position() == position {
+        return false;
+    };
+
+    lab_map.add_obstruction(position);
+
+    let mut visited_positions_and_directions = HashSet::new();
+
+    loop {
+        // A guard boxed in by the new obstruction turns on the spot forever.
+        if !lab_map.turn_until_unobstructed() {
+            return true;
+        }
+
+        let guard_position_and_direction = (
+            *lab_map.current_guard_position(),
+            *lab_map.current_guard_direction(),
+        );
+
+        if visited_positions_and_directions.contains(&guard_position_and_direction) {
+            return true;
+        }
+
+        match lab_map.step_forward() {
+            Ok(_) => {}
+            Err(StepForwardError::LeftMappedArea) => return false,
+            Err(StepForwardError::Obstruction) => panic!("Obstructions should not be hit"),
+        }
+
+        visited_positions_and_directions.insert(guard_position_and_direction);
+    }
+}
+
+/// The guard's patrol from part one, one step per frame.
+pub struct GuardWalk {
+    lab_map: LabMap,
+    steps: Vec<(Position, Direction)>,
+}
+
+impl GuardWalk {
+    pub fn new(lab_map: &LabMap) -> Self {
+        let mut lab_map = lab_map.clone();
+        lab_map.reset();
+
+        let mut steps = Vec::new();
+        let mut visited_positions_and_directions = HashSet::new();
+
+        loop {
+            let step = (
+                *lab_map.current_guard_position(),
+                *lab_map.current_guard_direction(),
+            );
+
+            if !visited_positions_and_directions.insert(step) {
+                break;
+            }
+
+            steps.push(step);
+
+            if !lab_map.turn_until_unobstructed() || lab_map.step_forward().is_err() {
+                break;
+            }
+        }
+
+        lab_map.reset();
+
+        Self { lab_map, steps }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GuardWalkCell {
+    Empty,
+    Obstruction,
+    Visited,
+    Guard(Direction),
+}
+
+impl Cell for GuardWalkCell {
+    fn symbol(&self) -> char {
+        match self {
+            GuardWalkCell::Empty => '.',
+            GuardWalkCell::Obstruction => '#',
+            GuardWalkCell::Visited => 'X',
+            GuardWalkCell::Guard(Direction::Up) => '^',
+            GuardWalkCell::Guard(Direction::Right) => '>',
+            GuardWalkCell::Guard(Direction::Down) => 'v',
+            GuardWalkCell::Guard(Direction::Left) => '<',
+            GuardWalkCell::Guard(_) => '*',
+        }
+    }
+
+    fn colour(&self) -> Colour {
+        match self {
+            GuardWalkCell::Empty => Colour::Grey,
+            GuardWalkCell::Obstruction => Colour::White,
+            GuardWalkCell::Visited => Colour::Yellow,
+            GuardWalkCell::Guard(_) => Colour::Red,
+        }
+    }
+}
+
+impl Frames for GuardWalk {
+    type Cell = GuardWalkCell;
+
+    fn frame_count(&self) -> usize {
+        self.steps.len()
+    }
+
+    fn frame(&mut self, index: usize) -> Grid<Self::Cell> {
+        let mut frame = self.lab_map.tiles().map(|tile| match tile {
+            Tile::Obstruction => GuardWalkCell::Obstruction,
+            _ => GuardWalkCell::Empty,
+        });
+
+        for (position, _) in &self.steps[..index] {
+            frame[*position] = GuardWalkCell::Visited;
+        }
+
+        let (position, direction) = self.steps[index];
+        frame[position] = GuardWalkCell::Guard(direction);
+
+        frame
+    }
+}
+
+mod lab_map {
+    use std::{
+        error::Error,
+        fmt::{self, Display},
+        str::FromStr,
+    };
+
+    use common::Location;
+    use geometry::{Direction, Position};
+    use grid::{Grid, ParseGridError};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum Tile {
+        Empty,
+        Obstruction,
+        Guard,
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LabMap {
+        tiles: Grid<Tile>,
+        initial_guard_position: Position,
+        guard_position: Position,
+        initial_guard_direction: Direction,
+        guard_direction: Direction,
+        added_obstruction_position: Option<Position>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub enum ParseLabMapError {
+        Grid(ParseGridError),
+        MissingGuard,
+        MultipleGuards(Location),
+    }
+
+    impl Display for ParseLabMapError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                ParseLabMapError::Grid(error) => write!(f, "{}", error),
+                ParseLabMapError::MissingGuard => write!(f, "no guard '^' found on the map"),
+                ParseLabMapError::MultipleGuards(location) => {
+                    write!(f, "{}: found a second guard '^'", location)
+                }
+            }
+        }
+    }
+
+    impl Error for ParseLabMapError {}
+
+    impl FromStr for LabMap {
+        type Err = ParseLabMapError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let tiles = Grid::parse_with(s, |character| match character {
+                '^' => Some(Tile::Guard),
+                '#' => Some(Tile::Obstruction),
+                '.' => Some(Tile::Empty),
+                _ => None,
+            })
+            .map_err(ParseLabMapError::Grid)?;
+
+            let mut guard_positions = tiles.positions_of(&Tile::Guard);
+
+            let guard_position = guard_positions
+                .next()
+                .ok_or(ParseLabMapError::MissingGuard)?;
+
+            if let Some(position) = guard_positions.next() {
+                return Err(ParseLabMapError::MultipleGuards(Location::new(
+                    position.y + 1,
+                    position.x + 1,
+                )));
+            }
+
+            let tiles = tiles.map(|&tile| match tile {
+                Tile::Guard => Tile::Empty,
+                tile => tile,
+            });
+
+            let guard_direction = Direction::Up;
+
+            Ok(Self {
+                tiles,
+                initial_guard_position: guard_position,
+                guard_position,
+                initial_guard_direction: guard_direction,
+                guard_direction,
+                added_obstruction_position: None,
+            })
+        }
+    }
+
+    pub enum StepForwardError {
+        Obstruction,
+        LeftMappedArea,
+    }
+
+    impl LabMap {
+        pub fn tiles(&self) -> &Grid<Tile> {
+            &self.tiles
+        }
+
+        pub fn added_obstruction_position(&self) -> Option<&Position> {
+            self.added_obstruction_position.as_ref()
+        }
+
+        pub fn width(&self) -> usize {
+            self.tiles.width()
+        }
+
+        pub fn height(&self) -> usize {
+            self.tiles.height()
+        }
+
+        pub fn current_guard_position(&self) -> &Position {
+            &self.guard_position
+        }
+
+        pub fn current_guard_direction(&self) -> &Direction {
+            &self.guard_direction
+        }
+
+        pub fn is_next_step_obstructed(&self) -> bool {
+            match self.next_step_position() {
+                Ok(_) => false,
+                Err(StepForwardError::LeftMappedArea) => false,
+                Err(StepForwardError::Obstruction) => true,
+            }
+        }
+
+        pub fn turn_right(&mut self) {
+            self.guard_direction = self.guard_direction.turn_right();
+        }
+
+        /// Turns right until the next step is clear, returning `false` if every direction is
+        /// obstructed.
+        pub fn turn_until_unobstructed(&mut self) -> bool {
+            for _ in 0..4 {
+                if !self.is_next_step_obstructed() {
+                    return true;
+                }
+
+                self.turn_right();
+            }
+
+            false
+        }
+
+        pub fn step_forward(&mut self) -> Result<(), StepForwardError> {
+            self.guard_position = self.next_step_position()?;
+            Ok(())
+        }
+
+        pub fn add_obstruction(&mut self, position: &Position) {
+            self.added_obstruction_position = Some(*position);
+        }
+
+        pub fn reset(&mut self) {
+            self.guard_position = self.initial_guard_position;
+            self.guard_direction = self.initial_guard_direction;
+            self.added_obstruction_position = None;
+        }
+
+        pub fn is_position_obstructed(&self, position: &Position) -> bool {
+            self.tiles.get(*position) == Some(&Tile::Obstruction)
+                || self.added_obstruction_position.as_ref() == Some(position)
+        }
+
+        fn next_step_position(&self) -> Result<Position, StepForwardError> {
+            let position = self
+                .guard_position
+                .translate(self.guard_direction)
+                .filter(|&position| self.tiles.contains(position))
+                .ok_or(StepForwardError::LeftMappedArea)?;
+
+            if self.is_position_obstructed(&position) {
+                Err(StepForwardError::Obstruction)
+            } else {
+                Ok(position)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("aoc-input/example-input.txt");
+
+    #[test]
+    fn distinct_guard_visit_positions_works() {
+        let lab_map = LabMap::from_str(EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(
+            distinct_guard_visit_positions(&lab_map, max_steps(&lab_map)),
+            Ok(41)
+        );
+    }
+
+    #[test]
+    fn distinct_guard_visit_positions_gives_up() {
+        let lab_map = LabMap::from_str(EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(
+            distinct_guard_visit_positions(&lab_map, 5),
+            Err(DidNotFinish::new(
+                "the guard is still walking after 5 steps, likely in a loop, having visited 6 \
+                 positions and now at 4,1 facing Up"
+            ))
+        );
+
+        let lab_map = LabMap::from_str(".#..\n..#.\n#...\n....\n.#..\n#^#.\n.#..").unwrap();
+        assert_eq!(
+            distinct_guard_visit_positions(&lab_map, max_steps(&lab_map)),
+            Err(DidNotFinish::new("the guard is boxed in at 1,5"))
+        );
+
+        let lab_map = LabMap::from_str(".#..\n...#\n#^..\n..#.").unwrap();
+        assert!(
+            distinct_guard_visit_positions(&lab_map, max_steps(&lab_map))
+                .unwrap_err()
+                .reason
+                .contains("likely in a loop")
+        );
+    }
+
+    #[test]
+    fn add_obstruction_potential_positions_works() {
+        assert_eq!(
+            add_obstruction_potential_positions(&LabMap::from_str(EXAMPLE_INPUT).unwrap()),
+            6
+        );
+    }
+
+    #[test]
+    fn guard_walk_works() {
+        let mut guard_walk = GuardWalk::new(&LabMap::from_str(EXAMPLE_INPUT).unwrap());
+
+        let last_frame = guard_walk.frame(guard_walk.frame_count() - 1);
+
+        assert_eq!(
+            last_frame
+                .iter()
+                .filter(|(_, cell)| matches!(
+                    cell,
+                    GuardWalkCell::Visited | GuardWalkCell::Guard(_)
+                ))
+                .count(),
+            41
+        );
+        assert_eq!(
+            guard_walk
+                .frame(0)
+                .position_of(&GuardWalkCell::Guard(Direction::Up)),
+            Some(Position::new(4, 6))
+        );
+    }
+
+    #[test]
+    fn from_str_rejects_malformed_maps() {
+        assert_eq!(
+            LabMap::from_str("..#\n.^.\n..").err(),
+            Some(ParseLabMapError::Grid(ParseGridError::RaggedLine {
+                line: 3,
+                expected: 3,
+                found: 2
+            }))
+        );
+        assert_eq!(
+            LabMap::from_str("..#\n...").err(),
+            Some(ParseLabMapError::MissingGuard)
+        );
+        assert_eq!(
+            LabMap::from_str(".^#\n..^").err(),
+            Some(ParseLabMapError::MultipleGuards(Location::new(2, 3)))
+        );
+    }
+
+    #[test]
+    fn check_works() {
+        assert!(Day6::check(EXAMPLE_INPUT).is_empty());
+        assert_eq!(
+            Day6::check(".^#\n.>^\n^.\n"),
+            vec![
+                Violation::at(
+                    Location::new(2, 2),
+                    "guard '>' is not facing up, only '^' is supported"
+                ),
+                Violation::at(Location::new(3, 1), "line is 2 characters long, expected 3"),
+                Violation::at(Location::new(2, 3), "found a second guard '^'"),
+                Violation::at(Location::new(3, 1), "found a second guard '^'"),
+            ]
+        );
+        assert_eq!(
+            Day6::check("..#\n..."),
+            vec![Violation::anywhere("no guard '^' found on the map")]
+        );
+    }
+}
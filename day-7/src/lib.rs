@@ -0,0 +1,361 @@
+use std::{collections::HashMap, io::BufRead};
+
+use common::{
+    check::check_lines,
+    input::{for_each_line, StreamError},
+    pool::Pool,
+    Located, Solution, StreamingSolution, Violation,
+};
+use input::parse_line;
+pub use input::{parse_input, Equation, ParseInputError};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Equation>;
+    type ParseError = Located<ParseInputError>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        parse_input(input)
+    }
+
+    fn check(input: &str) -> Vec<Violation> {
+        check_lines(input, |line| parse_line(input, line))
+    }
+
+    fn part_one(equations: &Self::Input) -> Self::PartOne {
+        possibly_true_calibration_results_total(equations)
+    }
+
+    fn part_two(equations: &Self::Input) -> Self::PartTwo {
+        possibly_true_calibration_results_with_concatenation_total(equations)
+    }
+}
+
+impl StreamingSolution for Day7 {
+    type RecordError = ParseInputError;
+
+    fn solve_reader(
+        reader: impl BufRead,
+    ) -> Result<(usize, usize), StreamError<Self::RecordError>> {
+        let mut operator_permutations_lookup = HashMap::new();
+        let mut operator_permutations_with_concatenation_lookup = HashMap::new();
+
+        let mut total = 0;
+        let mut total_with_concatenation = 0;
+
+        for_each_line(reader, |line| {
+            let equation = parse_line(line, line)?;
+
+            total += possibly_true_result(&equation, &OPERATORS, &mut operator_permutations_lookup)
+                .unwrap_or(0);
+            total_with_concatenation += possibly_true_result(
+                &equation,
+                &OPERATORS_WITH_CONCATENATION,
+                &mut operator_permutations_with_concatenation_lookup,
+            )
+            .unwrap_or(0);
+
+            Ok(())
+        })?;
+
+        Ok((total, total_with_concatenation))
+    }
+}
+
+const OPERATORS: [Operator; 2] = [Operator::Addition, Operator::Multiplication];
+
+const OPERATORS_WITH_CONCATENATION: [Operator; 3] = [
+    Operator::Addition,
+    Operator::Multiplication,
+    Operator::Concatenation,
+];
+
+/// Every permutation of operators generated so far, by the number of operators in them.
+type OperatorPermutationsLookup = HashMap<usize, Vec<Vec<Operator>>>;
+
+pub fn possibly_true_calibration_results_total(equations: &[Equation]) -> usize {
+    possibly_true_calibration_results_total_with(equations, &OPERATORS)
+}
+
+pub fn possibly_true_calibration_results_with_concatenation_total(equations: &[Equation]) -> usize {
+    possibly_true_calibration_results_total_with(equations, &OPERATORS_WITH_CONCATENATION)
+}
+
+fn possibly_true_calibration_results_total_with(
+    equations: &[Equation],
+    operators: &[Operator],
+) -> usize {
+    Pool::default()
+        .map_reduce_with(
+            equations,
+            OperatorPermutationsLookup::new,
+            |operator_permutations_lookup, equation| {
+                possibly_true_result(equation, operators, operator_permutations_lookup).unwrap_or(0)
+            },
+            |a, b| a + b,
+        )
+        .unwrap_or(0)
+}
+
+/// The equation's expected result, if some permutation of `operators` between its parts, evaluated
+/// left to right, produces it.
+fn possibly_true_result(
+    equation: &Equation,
+    operators: &[Operator],
+    operator_permutations_lookup: &mut OperatorPermutationsLookup,
+) -> Option<usize> {
+    let num_operators_required = equation.parts().len() - 1;
+
+    let operator_permutations = operator_permutations_lookup
+        .entry(num_operators_required)
+        .or_insert_with(|| generate_operator_permutations(num_operators_required, operators));
+
+    for permutation in operator_permutations {
+        let mut permutation_iterator = permutation.iter();
+
+        let result = equation
+            .parts()
+            .iter()
+            .cloned()
+            .reduce(|acc, p| match permutation_iterator.next().unwrap() {
+                Operator::Addition => acc + p,
+                Operator::Multiplication => acc * p,
+                Operator::Concatenation => {
+                    let p_log_10 = p.checked_ilog10().unwrap_or(0);
+                    acc * 10usize.pow(p_log_10 + 1) + p
+                }
+            })
+            .unwrap();
+
+        if &result == equation.expected_result() {
+            return Some(result);
+        }
+    }
+
+    None
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Operator {
+    Addition,
+    Multiplication,
+    Concatenation,
+}
+
+fn generate_operator_permutations(
+    num_operators_required: usize,
+    operators: &[Operator],
+) -> Vec<Vec<Operator>> {
+    let num_operator_permutations = operators
+        .len()
+        .checked_pow(num_operators_required.try_into().unwrap())
+        .unwrap();
+
+    (0..num_operator_permutations)
+        .map(|i| {
+            (0..num_operators_required)
+                .rev()
+                .map(|j| {
+                    operators[(i / (operators.len().pow(j.try_into().unwrap()))) % operators.len()]
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>()
+}
+
+mod input {
+    use std::{
+        error::Error,
+        fmt::{self, Display},
+    };
+
+    use common::Located;
+
+    pub struct Equation {
+        expected_result: usize,
+        parts: Vec<usize>,
+    }
+
+    impl Equation {
+        pub fn expected_result(&self) -> &usize {
+            &self.expected_result
+        }
+
+        pub fn parts(&self) -> &Vec<usize> {
+            &self.parts
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub enum ParseInputError {
+        MissingColon(String),
+        MissingParts(String),
+        InvalidNumber(String),
+    }
+
+    impl Display for ParseInputError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                ParseInputError::MissingColon(line) => {
+                    write!(f, "expected an equation like 190: 10 19, found {:?}", line)
+                }
+                ParseInputError::MissingParts(line) => {
+                    write!(
+                        f,
+                        "expected at least one number after the colon in {:?}",
+                        line
+                    )
+                }
+                ParseInputError::InvalidNumber(text) => write!(f, "invalid number {:?}", text),
+            }
+        }
+    }
+
+    impl Error for ParseInputError {}
+
+    pub fn parse_input(input: &str) -> Result<Vec<Equation>, Located<ParseInputError>> {
+        input
+            .lines()
+            .map(|line| parse_line(input, line))
+            .collect::<Result<Vec<_>, _>>()
+    }
+
+    pub(crate) fn parse_line(
+        input: &str,
+        line: &str,
+    ) -> Result<Equation, Located<ParseInputError>> {
+        let (result_str, parts_str) = line.split_once(":").ok_or_else(|| {
+            Located::at(input, line, ParseInputError::MissingColon(line.to_string()))
+        })?;
+
+        let parse_number = |text: &str| {
+            text.parse::<usize>().map_err(|_| {
+                Located::at(
+                    input,
+                    text,
+                    ParseInputError::InvalidNumber(text.to_string()),
+                )
+            })
+        };
+
+        let expected_result = parse_number(result_str)?;
+
+        let parts = parts_str
+            .split_whitespace()
+            .map(parse_number)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if parts.is_empty() {
+            return Err(Located::at(
+                input,
+                line,
+                ParseInputError::MissingParts(line.to_string()),
+            ));
+        }
+
+        Ok(Equation {
+            expected_result,
+            parts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use common::Location;
+
+    const EXAMPLE_INPUT: &str = include_str!("aoc-input/example-input.txt");
+
+    #[test]
+    fn possibly_true_calibration_results_total_works() {
+        assert_eq!(
+            possibly_true_calibration_results_total(&parse_input(EXAMPLE_INPUT).unwrap()),
+            3749
+        );
+    }
+
+    #[test]
+    fn possibly_true_calibration_results_with_concatenation_total_works() {
+        assert_eq!(
+            possibly_true_calibration_results_with_concatenation_total(
+                &parse_input(EXAMPLE_INPUT).unwrap()
+            ),
+            11387
+        );
+    }
+
+    #[test]
+    fn generate_operator_permutations_works() {
+        assert_eq!(
+            generate_operator_permutations(1, &[Operator::Addition, Operator::Multiplication]),
+            vec![vec![Operator::Addition], vec![Operator::Multiplication]]
+        );
+
+        assert_eq!(
+            generate_operator_permutations(2, &[Operator::Addition, Operator::Multiplication]),
+            vec![
+                vec![Operator::Addition, Operator::Addition],
+                vec![Operator::Addition, Operator::Multiplication],
+                vec![Operator::Multiplication, Operator::Addition],
+                vec![Operator::Multiplication, Operator::Multiplication]
+            ]
+        );
+
+        assert_eq!(
+            generate_operator_permutations(
+                2,
+                &[
+                    Operator::Addition,
+                    Operator::Multiplication,
+                    Operator::Concatenation
+                ]
+            ),
+            vec![
+                vec![Operator::Addition, Operator::Addition],
+                vec![Operator::Addition, Operator::Multiplication],
+                vec![Operator::Addition, Operator::Concatenation],
+                vec![Operator::Multiplication, Operator::Addition],
+                vec![Operator::Multiplication, Operator::Multiplication],
+                vec![Operator::Multiplication, Operator::Concatenation],
+                vec![Operator::Concatenation, Operator::Addition],
+                vec![Operator::Concatenation, Operator::Multiplication],
+                vec![Operator::Concatenation, Operator::Concatenation],
+            ]
+        );
+    }
+
+    #[test]
+    fn solve_reader_works() {
+        assert_eq!(
+            Day7::solve_reader(EXAMPLE_INPUT.as_bytes()).unwrap(),
+            (3749, 11387)
+        );
+
+        let repeated = EXAMPLE_INPUT.repeat(1000);
+        assert_eq!(
+            Day7::solve_reader(repeated.as_bytes()).unwrap(),
+            (3749 * 1000, 11387 * 1000)
+        );
+    }
+
+    #[test]
+    fn check_works() {
+        assert!(Day7::check(EXAMPLE_INPUT).is_empty());
+        assert_eq!(
+            Day7::check("190: 10 19\n3267 81 40\n83: 17 x\n"),
+            vec![
+                Violation::at(
+                    Location::new(2, 1),
+                    "expected an equation like 190: 10 19, found \"3267 81 40\""
+                ),
+                Violation::at(Location::new(3, 8), "invalid number \"x\""),
+            ]
+        );
+    }
+}
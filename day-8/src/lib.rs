@@ -0,0 +1,194 @@
+use std::{collections::HashSet, str::FromStr};
+
+use common::{Solution, Violation};
+use geometry::Position;
+use grid::{check_grid, ParseGridError};
+use models::mirrored_position;
+pub use models::AntennaMap;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = AntennaMap;
+    type ParseError = ParseGridError;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        AntennaMap::from_str(input)
+    }
+
+    fn check(input: &str) -> Vec<Violation> {
+        check_grid(input, |character| {
+            character == '.' || character.is_ascii_alphanumeric()
+        })
+        .into_iter()
+        .map(Violation::from)
+        .collect()
+    }
+
+    fn part_one(antenna_map: &Self::Input) -> Self::PartOne {
+        count_antinodes(antenna_map)
+    }
+
+    fn part_two(antenna_map: &Self::Input) -> Self::PartTwo {
+        count_antinodes_with_harmonics(antenna_map)
+    }
+}
+
+pub fn count_antinodes(antenna_map: &AntennaMap) -> usize {
+    let mut antinode_positions: HashSet<Position> = HashSet::new();
+
+    for frequency_antenna_positions in antenna_map.antenna_positions().values() {
+        for antenna_position in frequency_antenna_positions {
+            for comparison_antenna_position in frequency_antenna_positions {
+                if antenna_position == comparison_antenna_position {
+                    continue;
+                }
+
+                if let Some(mirrored_position) =
+                    mirrored_position(antenna_position, comparison_antenna_position, &1)
+                        .filter(|position| antenna_map.contains(position))
+                {
+                    antinode_positions.insert(mirrored_position);
+                }
+            }
+        }
+    }
+
+    antinode_positions.len()
+}
+
+pub fn count_antinodes_with_harmonics(antenna_map: &AntennaMap) -> usize {
+    let mut antinode_positions: HashSet<Position> = HashSet::new();
+
+    for frequency_antenna_positions in antenna_map.antenna_positions().values() {
+        for antenna_position in frequency_antenna_positions {
+            for comparison_antenna_position in frequency_antenna_positions {
+                if antenna_position == comparison_antenna_position {
+                    continue;
+                }
+
+                antinode_positions.insert(*antenna_position);
+
+                let mut multiplier = 1;
+
+                while let Some(mirrored_position) =
+                    mirrored_position(antenna_position, comparison_antenna_position, &multiplier)
+                        .filter(|position| antenna_map.contains(position))
+                {
+                    antinode_positions.insert(mirrored_position);
+
+                    multiplier += 1;
+                }
+            }
+        }
+    }
+
+    antinode_positions.len()
+}
+
+mod models {
+    use std::{collections::HashMap, str::FromStr};
+
+    use geometry::{Position, Vector};
+    use grid::{Grid, ParseGridError};
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub(crate) struct Frequency(char);
+
+    /// Mirrors `position` through `comparison_antenna_position`, `multiplier` times over.
+    pub(crate) fn mirrored_position(
+        position: &Position,
+        comparison_antenna_position: &Position,
+        multiplier: &usize,
+    ) -> Option<Position> {
+        let difference = Vector::from(*comparison_antenna_position) - Vector::from(*position);
+        let multiplier = *multiplier as isize;
+
+        position.offset(difference * (multiplier + 1))
+    }
+
+    pub struct AntennaMap {
+        grid: Grid<char>,
+        antenna_positions: HashMap<Frequency, Vec<Position>>,
+    }
+
+    impl FromStr for AntennaMap {
+        type Err = ParseGridError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let grid = Grid::parse_with(s, |character| {
+                (character == '.' || character.is_ascii_alphanumeric()).then_some(character)
+            })?;
+
+            let mut antenna_positions: HashMap<Frequency, Vec<Position>> = HashMap::new();
+
+            for (position, &character) in grid.iter() {
+                if character == '.' {
+                    continue;
+                }
+
+                let frequency = Frequency(character);
+
+                antenna_positions
+                    .entry(frequency)
+                    .or_default()
+                    .push(position);
+            }
+
+            Ok(AntennaMap {
+                grid,
+                antenna_positions,
+            })
+        }
+    }
+
+    impl AntennaMap {
+        pub(crate) fn contains(&self, position: &Position) -> bool {
+            self.grid.contains(*position)
+        }
+
+        pub(crate) fn antenna_positions(&self) -> &HashMap<Frequency, Vec<Position>> {
+            &self.antenna_positions
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use common::Location;
+
+    const EXAMPLE_INPUT: &str = include_str!("aoc-input/example-input.txt");
+
+    #[test]
+    fn count_antinodes_works() {
+        assert_eq!(
+            count_antinodes(&AntennaMap::from_str(EXAMPLE_INPUT).unwrap()),
+            14
+        );
+    }
+
+    #[test]
+    fn count_antinodes_with_harmonics_works() {
+        assert_eq!(
+            count_antinodes_with_harmonics(&AntennaMap::from_str(EXAMPLE_INPUT).unwrap()),
+            34
+        );
+    }
+
+    #[test]
+    fn check_works() {
+        assert!(Day8::check(EXAMPLE_INPUT).is_empty());
+        assert_eq!(
+            Day8::check("..a.\n.#..\n...!"),
+            vec![
+                Violation::at(Location::new(2, 2), "unexpected character '#'"),
+                Violation::at(Location::new(3, 4), "unexpected character '!'"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,696 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    mem,
+};
+
+use common::{Located, Solution, Violation};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = (Vec<FileBlock>, usize);
+    type ParseError = Located<ParseInputError>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        parse_input(input)
+    }
+
+    fn check(input: &str) -> Vec<Violation> {
+        let disk_map = input.trim();
+
+        disk_map
+            .char_indices()
+            .filter(|(_, character)| !character.is_ascii_digit())
+            .map(|(offset, character)| {
+                Violation::from(Located::at(
+                    input,
+                    &disk_map[offset..],
+                    ParseInputError(character),
+                ))
+            })
+            .collect()
+    }
+
+    fn part_one((file_blocks, len): &Self::Input) -> Self::PartOne {
+        compacted_filesystem_checksum(file_blocks, *len)
+    }
+
+    fn part_two((file_blocks, len): &Self::Input) -> Self::PartTwo {
+        contiguous_compacted_filesystem_checksum(file_blocks, *len)
+    }
+}
+
+pub fn compacted_filesystem_checksum(file_blocks: &[FileBlock], len: usize) -> usize {
+    let mut expanded_file_blocks = expand_file_blocks(file_blocks, len);
+
+    compact_expanded_file_blocks(&mut expanded_file_blocks[..]);
+
+    expanded_file_blocks
+        .iter()
+        .enumerate()
+        .fold(0usize, |acc, (i, file_block)| {
+            let Some(file_block_id) = file_block else {
+                return acc;
+            };
+            acc + (i * file_block_id)
+        })
+}
+
+pub fn contiguous_compacted_filesystem_checksum(file_blocks: &[FileBlock], len: usize) -> usize {
+    let compacted_file_blocks = compact_file_blocks(file_blocks);
+
+    let compacted_expanded_file_blocks = expand_file_blocks(&compacted_file_blocks[..], len);
+
+    compacted_expanded_file_blocks
+        .iter()
+        .enumerate()
+        .fold(0usize, |acc, (i, file_block)| {
+            let Some(file_block_id) = file_block else {
+                return acc;
+            };
+            acc + (i * file_block_id)
+        })
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileBlock {
+    id: usize,
+    offset: usize,
+    len: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParseInputError(char);
+
+impl Display for ParseInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected a digit in the disk map, found {:?}", self.0)
+    }
+}
+
+impl Error for ParseInputError {}
+
+pub fn parse_input(input: &str) -> Result<(Vec<FileBlock>, usize), Located<ParseInputError>> {
+    let mut result = Vec::new();
+
+    let mut next_id = 0;
+    let mut next_offset = 0;
+
+    let disk_map = input.trim();
+
+    for (i, (offset, character)) in disk_map.char_indices().enumerate() {
+        let is_file = i % 2 == 0;
+
+        let len = character
+            .to_digit(10)
+            .ok_or_else(|| Located::at(input, &disk_map[offset..], ParseInputError(character)))?
+            as usize;
+
+        if len == 0 {
+            continue;
+        }
+
+        if is_file {
+            result.push(FileBlock {
+                id: next_id,
+                offset: next_offset,
+                len,
+            });
+
+            next_id += 1;
+        }
+
+        next_offset += len;
+    }
+
+    Ok((result, next_offset))
+}
+
+pub fn expand_file_blocks(file_blocks: &[FileBlock], len: usize) -> Vec<Option<usize>> {
+    let mut result = Vec::new();
+
+    for file_block in file_blocks {
+        while file_block.offset > result.len() {
+            result.push(None);
+        }
+
+        for _ in 0..file_block.len {
+            result.push(Some(file_block.id));
+        }
+    }
+
+    while result.len() < len {
+        result.push(None);
+    }
+
+    result
+}
+
+pub fn compact_expanded_file_blocks(file_blocks: &mut [Option<usize>]) {
+    let mut file_block_iter_mut = file_blocks.iter_mut();
+
+    while let Some(file_block) = file_block_iter_mut.next() {
+        if file_block.is_some() {
+            continue;
+        };
+
+        while let Some(end_file_block) = file_block_iter_mut.next_back() {
+            if end_file_block.is_none() {
+                continue;
+            }
+
+            mem::swap(file_block, end_file_block);
+            break;
+        }
+    }
+}
+
+pub fn compact_file_blocks(file_blocks: &[FileBlock]) -> Vec<FileBlock> {
+    let mut result = file_blocks.to_vec();
+    result.sort_by_key(|b| b.offset);
+
+    let ids = file_blocks.iter().map(|b| b.id);
+
+    for id in ids.rev() {
+        let file_block_to_move = file_blocks.iter().find(|b| b.id == id).unwrap();
+
+        let mut offset_to_move_to: Option<usize> = None;
+
+        let mut windowed_result = result.windows(2);
+        while let Some([a, b]) = windowed_result.next() {
+            if b.offset > file_block_to_move.offset {
+                break;
+            }
+
+            let empty_space_offset = a.offset + a.len;
+            let empty_space_between = b.offset - empty_space_offset;
+
+            if empty_space_between < file_block_to_move.len {
+                continue;
+            }
+
+            offset_to_move_to = Some(empty_space_offset);
+            break;
+        }
+
+        if let Some(offset) = offset_to_move_to {
+            let file_block = result.iter_mut().find(|b| b.id == id).unwrap();
+            file_block.offset = offset;
+
+            result.sort_by_key(|b| b.offset);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use common::Location;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("aoc-input/example-input.txt");
+
+    #[test]
+    fn compacted_filesystem_checksum_works() {
+        let (file_blocks, len) = parse_input(EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(compacted_filesystem_checksum(&file_blocks, len), 1928);
+    }
+
+    #[test]
+    fn contiguous_compacted_filesystem_checksum_works() {
+        let (file_blocks, len) = parse_input(EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(
+            contiguous_compacted_filesystem_checksum(&file_blocks, len),
+            2858
+        );
+    }
+
+    #[test]
+    fn parse_input_works() {
+        assert_eq!(
+            parse_input(EXAMPLE_INPUT).unwrap(),
+            (
+                vec![
+                    FileBlock {
+                        id: 0,
+                        offset: 0,
+                        len: 2
+                    },
+                    FileBlock {
+                        id: 1,
+                        offset: 5,
+                        len: 3
+                    },
+                    FileBlock {
+                        id: 2,
+                        offset: 11,
+                        len: 1
+                    },
+                    FileBlock {
+                        id: 3,
+                        offset: 15,
+                        len: 3
+                    },
+                    FileBlock {
+                        id: 4,
+                        offset: 19,
+                        len: 2
+                    },
+                    FileBlock {
+                        id: 5,
+                        offset: 22,
+                        len: 4
+                    },
+                    FileBlock {
+                        id: 6,
+                        offset: 27,
+                        len: 4
+                    },
+                    FileBlock {
+                        id: 7,
+                        offset: 32,
+                        len: 3
+                    },
+                    FileBlock {
+                        id: 8,
+                        offset: 36,
+                        len: 4
+                    },
+                    FileBlock {
+                        id: 9,
+                        offset: 40,
+                        len: 2
+                    },
+                ],
+                42
+            )
+        )
+    }
+
+    #[test]
+    fn expand_file_blocks_works() {
+        assert_eq!(
+            expand_file_blocks(
+                &[
+                    FileBlock {
+                        id: 0,
+                        offset: 0,
+                        len: 2
+                    },
+                    FileBlock {
+                        id: 1,
+                        offset: 5,
+                        len: 3
+                    },
+                    FileBlock {
+                        id: 2,
+                        offset: 11,
+                        len: 1
+                    },
+                    FileBlock {
+                        id: 3,
+                        offset: 15,
+                        len: 3
+                    },
+                    FileBlock {
+                        id: 4,
+                        offset: 19,
+                        len: 2
+                    },
+                    FileBlock {
+                        id: 5,
+                        offset: 22,
+                        len: 4
+                    },
+                    FileBlock {
+                        id: 6,
+                        offset: 27,
+                        len: 4
+                    },
+                    FileBlock {
+                        id: 7,
+                        offset: 32,
+                        len: 3
+                    },
+                    FileBlock {
+                        id: 8,
+                        offset: 36,
+                        len: 4
+                    },
+                    FileBlock {
+                        id: 9,
+                        offset: 40,
+                        len: 2
+                    },
+                ],
+                42
+            ),
+            vec![
+                Some(0),
+                Some(0),
+                None,
+                None,
+                None,
+                Some(1),
+                Some(1),
+                Some(1),
+                None,
+                None,
+                None,
+                Some(2),
+                None,
+                None,
+                None,
+                Some(3),
+                Some(3),
+                Some(3),
+                None,
+                Some(4),
+                Some(4),
+                None,
+                Some(5),
+                Some(5),
+                Some(5),
+                Some(5),
+                None,
+                Some(6),
+                Some(6),
+                Some(6),
+                Some(6),
+                None,
+                Some(7),
+                Some(7),
+                Some(7),
+                None,
+                Some(8),
+                Some(8),
+                Some(8),
+                Some(8),
+                Some(9),
+                Some(9),
+            ]
+        );
+    }
+
+    #[test]
+    fn compact_expanded_file_blocks_works() {
+        let mut expanded_file_blocks = vec![
+            Some(0),
+            Some(0),
+            None,
+            None,
+            None,
+            Some(1),
+            Some(1),
+            Some(1),
+            None,
+            None,
+            None,
+            Some(2),
+            None,
+            None,
+            None,
+            Some(3),
+            Some(3),
+            Some(3),
+            None,
+            Some(4),
+            Some(4),
+            None,
+            Some(5),
+            Some(5),
+            Some(5),
+            Some(5),
+            None,
+            Some(6),
+            Some(6),
+            Some(6),
+            Some(6),
+            None,
+            Some(7),
+            Some(7),
+            Some(7),
+            None,
+            Some(8),
+            Some(8),
+            Some(8),
+            Some(8),
+            Some(9),
+            Some(9),
+        ];
+
+        compact_expanded_file_blocks(&mut expanded_file_blocks[..]);
+
+        assert_eq!(
+            expanded_file_blocks,
+            vec![
+                Some(0),
+                Some(0),
+                Some(9),
+                Some(9),
+                Some(8),
+                Some(1),
+                Some(1),
+                Some(1),
+                Some(8),
+                Some(8),
+                Some(8),
+                Some(2),
+                Some(7),
+                Some(7),
+                Some(7),
+                Some(3),
+                Some(3),
+                Some(3),
+                Some(6),
+                Some(4),
+                Some(4),
+                Some(6),
+                Some(5),
+                Some(5),
+                Some(5),
+                Some(5),
+                Some(6),
+                Some(6),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn compact_file_blocks_works() {
+        let file_blocks = vec![
+            FileBlock {
+                id: 0,
+                offset: 0,
+                len: 2,
+            },
+            FileBlock {
+                id: 1,
+                offset: 5,
+                len: 3,
+            },
+            FileBlock {
+                id: 2,
+                offset: 11,
+                len: 1,
+            },
+            FileBlock {
+                id: 3,
+                offset: 15,
+                len: 3,
+            },
+            FileBlock {
+                id: 4,
+                offset: 19,
+                len: 2,
+            },
+            FileBlock {
+                id: 5,
+                offset: 22,
+                len: 4,
+            },
+            FileBlock {
+                id: 6,
+                offset: 27,
+                len: 4,
+            },
+            FileBlock {
+                id: 7,
+                offset: 32,
+                len: 3,
+            },
+            FileBlock {
+                id: 8,
+                offset: 36,
+                len: 4,
+            },
+            FileBlock {
+                id: 9,
+                offset: 40,
+                len: 2,
+            },
+        ];
+
+        assert_eq!(
+            compact_file_blocks(&file_blocks[..]),
+            vec![
+                FileBlock {
+                    id: 0,
+                    offset: 0,
+                    len: 2
+                },
+                FileBlock {
+                    id: 9,
+                    offset: 2,
+                    len: 2
+                },
+                FileBlock {
+                    id: 2,
+                    offset: 4,
+                    len: 1
+                },
+                FileBlock {
+                    id: 1,
+                    offset: 5,
+                    len: 3
+                },
+                FileBlock {
+                    id: 7,
+                    offset: 8,
+                    len: 3
+                },
+                FileBlock {
+                    id: 4,
+                    offset: 12,
+                    len: 2
+                },
+                FileBlock {
+                    id: 3,
+                    offset: 15,
+                    len: 3
+                },
+                FileBlock {
+                    id: 5,
+                    offset: 22,
+                    len: 4
+                },
+                FileBlock {
+                    id: 6,
+                    offset: 27,
+                    len: 4
+                },
+                FileBlock {
+                    id: 8,
+                    offset: 36,
+                    len: 4
+                },
+            ]
+        );
+    }
+
+    fn disk_map() -> impl Strategy<Value = String> {
+        proptest::collection::vec(0..10u32, 0..40).prop_map(|digits| {
+            digits
+                .into_iter()
+                .map(|digit| char::from_digit(digit, 10).unwrap())
+                .collect()
+        })
+    }
+
+    fn ids_and_lens(file_blocks: &[FileBlock]) -> Vec<(usize, usize)> {
+        let mut ids_and_lens = file_blocks
+            .iter()
+            .map(|b| (b.id, b.len))
+            .collect::<Vec<_>>();
+        ids_and_lens.sort();
+        ids_and_lens
+    }
+
+    proptest! {
+        #[test]
+        fn compact_file_blocks_keeps_files_and_never_moves_them_right(disk_map in disk_map()) {
+            let (file_blocks, len) = parse_input(&disk_map).unwrap();
+
+            let compacted = compact_file_blocks(&file_blocks);
+
+            prop_assert_eq!(ids_and_lens(&compacted), ids_and_lens(&file_blocks));
+
+            for file_block in &compacted {
+                let original = file_blocks.iter().find(|b| b.id == file_block.id).unwrap();
+                prop_assert!(file_block.offset <= original.offset);
+            }
+
+            for pair in compacted.windows(2) {
+                prop_assert!(pair[0].offset + pair[0].len <= pair[1].offset);
+            }
+
+            if let Some(last) = compacted.last() {
+                prop_assert!(last.offset + last.len <= len);
+            }
+        }
+
+        #[test]
+        fn compact_expanded_file_blocks_leaves_no_gaps(disk_map in disk_map()) {
+            let (file_blocks, len) = parse_input(&disk_map).unwrap();
+            let expanded = expand_file_blocks(&file_blocks, len);
+
+            let mut compacted = expanded.clone();
+            compact_expanded_file_blocks(&mut compacted);
+
+            let file_count = expanded.iter().flatten().count();
+            prop_assert!(compacted[..file_count].iter().all(Option::is_some));
+            prop_assert!(compacted[file_count..].iter().all(Option::is_none));
+
+            let mut before = expanded.into_iter().flatten().collect::<Vec<_>>();
+            let mut after = compacted.into_iter().flatten().collect::<Vec<_>>();
+            before.sort();
+            after.sort();
+            prop_assert_eq!(before, after);
+        }
+    }
+
+    #[test]
+    fn check_works() {
+        assert!(Day9::check(EXAMPLE_INPUT).is_empty());
+        assert_eq!(
+            Day9::check("12x4\n5-6\n"),
+            vec![
+                Violation::at(
+                    Location::new(1, 3),
+                    "expected a digit in the disk map, found 'x'"
+                ),
+                Violation::at(
+                    Location::new(1, 5),
+                    "expected a digit in the disk map, found '\\n'"
+                ),
+                Violation::at(
+                    Location::new(2, 2),
+                    "expected a digit in the disk map, found '-'"
+                ),
+            ]
+        );
+    }
+}
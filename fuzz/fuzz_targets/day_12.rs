@@ -0,0 +1,10 @@
+#![no_main]
+
+use common::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(parsed) = day_12::Day12::parse(input) {
+        let _ = day_12::Day12::part_one(&parsed);
+    }
+});
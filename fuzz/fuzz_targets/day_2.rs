@@ -0,0 +1,10 @@
+#![no_main]
+
+use common::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(parsed) = day_2::Day2::parse(input) {
+        let _ = day_2::Day2::part_one(&parsed);
+    }
+});
@@ -0,0 +1,17 @@
+#!/bin/sh
+# Seeds each day's fuzz corpus with its example inputs, so fuzzing starts from well-formed input.
+set -eu
+
+root=$(cd "$(dirname "$0")/.." && pwd)
+
+for day_dir in "$root"/day-*; do
+    day=${day_dir##*/day-}
+    corpus="$root/fuzz/corpus/day_$day"
+
+    mkdir -p "$corpus"
+    for example in "$day_dir"/src/aoc-input/example-input*.txt; do
+        if [ -e "$example" ]; then
+            cp "$example" "$corpus/"
+        fi
+    done
+done
@@ -0,0 +1,269 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// One of the 8 compass directions on a grid where `y` grows downwards.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The 4 orthogonal directions, clockwise from up.
+    pub const fn orthogonal() -> [Direction; 4] {
+        [
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+        ]
+    }
+
+    /// The 4 diagonal directions, clockwise from up-right.
+    pub const fn diagonal() -> [Direction; 4] {
+        [
+            Direction::UpRight,
+            Direction::DownRight,
+            Direction::DownLeft,
+            Direction::UpLeft,
+        ]
+    }
+
+    /// All 8 directions, clockwise from up.
+    pub const fn all() -> [Direction; 8] {
+        [
+            Direction::Up,
+            Direction::UpRight,
+            Direction::Right,
+            Direction::DownRight,
+            Direction::Down,
+            Direction::DownLeft,
+            Direction::Left,
+            Direction::UpLeft,
+        ]
+    }
+
+    pub fn is_orthogonal(&self) -> bool {
+        matches!(
+            self,
+            Direction::Up | Direction::Right | Direction::Down | Direction::Left
+        )
+    }
+
+    /// Rotates the direction by 90 degrees clockwise.
+    pub fn turn_right(&self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// Rotates the direction by 90 degrees counterclockwise.
+    pub fn turn_left(&self) -> Direction {
+        self.rotate(6)
+    }
+
+    pub fn reverse(&self) -> Direction {
+        self.rotate(4)
+    }
+
+    /// The unit step taken when moving one cell in this direction.
+    pub fn vector(&self) -> Vector {
+        let (x, y) = match self {
+            Direction::Up => (0, -1),
+            Direction::UpRight => (1, -1),
+            Direction::Right => (1, 0),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (0, 1),
+            Direction::DownLeft => (-1, 1),
+            Direction::Left => (-1, 0),
+            Direction::UpLeft => (-1, -1),
+        };
+
+        Vector::new(x, y)
+    }
+
+    fn rotate(&self, eighths: usize) -> Direction {
+        let all = Direction::all();
+        let index = all.iter().position(|direction| direction == self).unwrap();
+
+        all[(index + eighths) % all.len()]
+    }
+}
+
+/// A position on a grid, with `x` growing rightwards and `y` growing downwards.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Position {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the adjacent position in the given direction, or `None` if either coordinate
+    /// would become negative.
+    pub fn translate(&self, direction: Direction) -> Option<Position> {
+        self.offset(direction.vector())
+    }
+
+    /// Returns the position moved by `vector`, or `None` if either coordinate would become
+    /// negative.
+    pub fn offset(&self, vector: Vector) -> Option<Position> {
+        Some(Position::new(
+            self.x.checked_add_signed(vector.x)?,
+            self.y.checked_add_signed(vector.y)?,
+        ))
+    }
+
+    pub fn manhattan_distance(&self, other: &Position) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev_distance(&self, other: &Position) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+/// A signed displacement or position, for arithmetic that may leave the grid.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Vector {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Vector {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan_distance(&self, other: &Vector) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev_distance(&self, other: &Vector) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+impl From<Position> for Vector {
+    fn from(position: Position) -> Self {
+        Vector::new(
+            position.x.try_into().expect("x to fit an isize"),
+            position.y.try_into().expect("y to fit an isize"),
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct NegativeCoordinateError;
+
+impl TryFrom<Vector> for Position {
+    type Error = NegativeCoordinateError;
+
+    fn try_from(vector: Vector) -> Result<Self, Self::Error> {
+        Ok(Position::new(
+            vector.x.try_into().map_err(|_| NegativeCoordinateError)?,
+            vector.y.try_into().map_err(|_| NegativeCoordinateError)?,
+        ))
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Self::Output {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Self::Output {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, scalar: isize) -> Self::Output {
+        Vector::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Self::Output {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn direction_turns_work() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::UpRight.turn_right(), Direction::DownRight);
+        assert_eq!(Direction::Down.reverse(), Direction::Up);
+        assert_eq!(Direction::DownLeft.reverse(), Direction::UpRight);
+
+        for direction in Direction::all() {
+            assert_eq!(direction.vector(), -direction.reverse().vector());
+            assert_eq!(direction.turn_left().turn_right(), direction);
+        }
+    }
+
+    #[test]
+    fn position_translate_works() {
+        let position = Position::new(0, 1);
+
+        assert_eq!(position.translate(Direction::Up), Some(Position::new(0, 0)));
+        assert_eq!(position.translate(Direction::Left), None);
+        assert_eq!(
+            position.translate(Direction::DownRight),
+            Some(Position::new(1, 2))
+        );
+        assert_eq!(position.translate(Direction::UpLeft), None);
+    }
+
+    #[test]
+    fn conversions_work() {
+        assert_eq!(Vector::from(Position::new(3, 4)), Vector::new(3, 4));
+        assert_eq!(
+            Position::try_from(Vector::new(3, 4)),
+            Ok(Position::new(3, 4))
+        );
+        assert_eq!(
+            Position::try_from(Vector::new(-1, 4)),
+            Err(NegativeCoordinateError)
+        );
+    }
+
+    #[test]
+    fn distances_work() {
+        let a = Position::new(1, 5);
+        let b = Position::new(4, 1);
+
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+        assert_eq!(
+            Vector::new(-2, 3).manhattan_distance(&Vector::new(1, -1)),
+            7
+        );
+        assert_eq!(
+            Vector::new(-2, 3).chebyshev_distance(&Vector::new(1, -1)),
+            4
+        );
+    }
+}
@@ -0,0 +1,496 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use common::{Location, Violation};
+use geometry::{Direction, Position};
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseGridError {
+    Empty,
+    RaggedLine {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    InvalidCharacter {
+        line: usize,
+        column: usize,
+        character: char,
+    },
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGridError::Empty => write!(f, "grid is empty"),
+            ParseGridError::RaggedLine {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {} is {} characters long, expected {}",
+                line, found, expected
+            ),
+            ParseGridError::InvalidCharacter {
+                line,
+                column,
+                character,
+            } => write!(
+                f,
+                "unexpected character {:?} at line {}, column {}",
+                character, line, column
+            ),
+        }
+    }
+}
+
+impl Error for ParseGridError {}
+
+impl From<ParseGridError> for Violation {
+    fn from(error: ParseGridError) -> Self {
+        match error {
+            ParseGridError::Empty => Violation::anywhere(error),
+            ParseGridError::RaggedLine {
+                line,
+                expected,
+                found,
+            } => Violation::at(
+                Location::new(line, 1),
+                format!("line is {} characters long, expected {}", found, expected),
+            ),
+            ParseGridError::InvalidCharacter {
+                line,
+                column,
+                character,
+            } => Violation::at(
+                Location::new(line, column),
+                format!("unexpected character {:?}", character),
+            ),
+        }
+    }
+}
+
+/// Checks `s` the way [`Grid::parse_with`] parses it, but reports every ragged line and invalid
+/// character instead of stopping at the first. Every line is compared with the first line's
+/// width.
+pub fn check_grid(s: &str, mut is_valid: impl FnMut(char) -> bool) -> Vec<ParseGridError> {
+    let mut errors = Vec::new();
+    let mut width = None;
+
+    for (y, line) in s.lines().enumerate() {
+        let line_width = line.chars().count();
+
+        match width {
+            None => width = Some(line_width),
+            Some(width) if width != line_width => errors.push(ParseGridError::RaggedLine {
+                line: y + 1,
+                expected: width,
+                found: line_width,
+            }),
+            Some(_) => {}
+        }
+
+        for (x, character) in line.chars().enumerate() {
+            if !is_valid(character) {
+                errors.push(ParseGridError::InvalidCharacter {
+                    line: y + 1,
+                    column: x + 1,
+                    character,
+                });
+            }
+        }
+    }
+
+    if matches!(width, None | Some(0)) {
+        errors.push(ParseGridError::Empty);
+    }
+
+    errors
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses a grid with one line per row, mapping each character to a cell. Lines and columns
+    /// in errors are 1-based.
+    pub fn parse_with(
+        s: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseGridError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (y, line) in s.lines().enumerate() {
+            let line_width = line.chars().count();
+
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(ParseGridError::RaggedLine {
+                        line: y + 1,
+                        expected: width,
+                        found: line_width,
+                    });
+                }
+                Some(_) => {}
+            }
+
+            for (x, character) in line.chars().enumerate() {
+                let cell = f(character).ok_or(ParseGridError::InvalidCharacter {
+                    line: y + 1,
+                    column: x + 1,
+                    character,
+                })?;
+
+                cells.push(cell);
+            }
+
+            height += 1;
+        }
+
+        match width {
+            None | Some(0) => Err(ParseGridError::Empty),
+            Some(width) => Ok(Self {
+                width,
+                height,
+                cells,
+            }),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if !self.contains(position) {
+            return None;
+        }
+
+        self.cells.get(position.y * self.width + position.x)
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if !self.contains(position) {
+            return None;
+        }
+
+        self.cells.get_mut(position.y * self.width + position.x)
+    }
+
+    /// Iterates over every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Position::new(x, y)))
+    }
+
+    /// Iterates over every cell in the grid along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Iterates over the orthogonally adjacent positions that are inside the grid.
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, Direction::orthogonal())
+    }
+
+    /// Iterates over the orthogonally and diagonally adjacent positions that are inside the
+    /// grid.
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, Direction::all())
+    }
+
+    fn neighbours<const N: usize>(
+        &self,
+        position: Position,
+        directions: [Direction; N],
+    ) -> impl Iterator<Item = Position> + '_ {
+        directions
+            .into_iter()
+            .filter_map(move |direction| position.translate(direction))
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y >= self.height {
+            return None;
+        }
+
+        Some(&self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = if x < self.width {
+            &self.cells[x..]
+        } else {
+            &[]
+        };
+
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    /// Iterates over the positions from `start` (inclusive) in steps towards `direction`, until
+    /// the edge of the grid is reached.
+    pub fn ray(
+        &self,
+        start: Position,
+        direction: Direction,
+    ) -> impl Iterator<Item = Position> + '_ {
+        let start = Some(start).filter(|&start| self.contains(start));
+
+        std::iter::successors(start, move |position| {
+            position
+                .translate(direction)
+                .filter(|&position| self.contains(position))
+        })
+    }
+
+    pub fn position_of(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.positions_of(value).next()
+    }
+
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+{
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, |character| T::try_from(character).ok())
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "position {:?} is outside of the {}x{} grid",
+                position, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(position).unwrap_or_else(|| {
+            panic!(
+                "position {:?} is outside of the {}x{} grid",
+                position, width, height
+            )
+        })
+    }
+}
+
+impl<T> Display for Grid<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_GRID: &str = "abc\ndef\n";
+
+    #[test]
+    fn from_str_works() {
+        let grid = Grid::<char>::from_str(EXAMPLE_GRID).unwrap();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(Position::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(Position::new(3, 1)), None);
+        assert_eq!(grid.get(Position::new(0, 2)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    fn parse_with_rejects_invalid_input() {
+        assert_eq!(
+            Grid::<char>::from_str("abc\nde\n"),
+            Err(ParseGridError::RaggedLine {
+                line: 2,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            Grid::parse_with("..\n.x", |c| (c == '.').then_some(())),
+            Err(ParseGridError::InvalidCharacter {
+                line: 2,
+                column: 2,
+                character: 'x'
+            })
+        );
+        assert_eq!(Grid::<char>::from_str(""), Err(ParseGridError::Empty));
+    }
+
+    #[test]
+    fn check_grid_works() {
+        let errors = check_grid("...\n.x\n..y\n", |c| c == '.');
+
+        assert_eq!(
+            errors,
+            vec![
+                ParseGridError::RaggedLine {
+                    line: 2,
+                    expected: 3,
+                    found: 2
+                },
+                ParseGridError::InvalidCharacter {
+                    line: 2,
+                    column: 2,
+                    character: 'x'
+                },
+                ParseGridError::InvalidCharacter {
+                    line: 3,
+                    column: 3,
+                    character: 'y'
+                },
+            ]
+        );
+        assert_eq!(
+            Violation::from(errors[1].clone()).to_string(),
+            "line 2, column 2: unexpected character 'x'"
+        );
+        assert!(check_grid("..\n..", |c| c == '.').is_empty());
+        assert_eq!(check_grid("", |_| true), vec![ParseGridError::Empty]);
+    }
+
+    #[test]
+    fn neighbours_work() {
+        let grid = Grid::<char>::from_str(EXAMPLE_GRID).unwrap();
+
+        assert_eq!(
+            grid.neighbours_4(Position::new(0, 0)).collect::<Vec<_>>(),
+            vec![Position::new(1, 0), Position::new(0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours_8(Position::new(1, 1)).collect::<Vec<_>>(),
+            vec![
+                Position::new(1, 0),
+                Position::new(2, 0),
+                Position::new(2, 1),
+                Position::new(0, 1),
+                Position::new(0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn rows_columns_and_rays_work() {
+        let grid = Grid::<char>::from_str(EXAMPLE_GRID).unwrap();
+
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(
+            grid.ray(Position::new(0, 0), Direction::DownRight)
+                .map(|position| grid[position])
+                .collect::<String>(),
+            "ae"
+        );
+        assert_eq!(
+            grid.ray(Position::new(2, 0), Direction::Left)
+                .map(|position| grid[position])
+                .collect::<String>(),
+            "cba"
+        );
+    }
+
+    #[test]
+    fn find_and_map_work() {
+        let mut grid = Grid::<char>::from_str(EXAMPLE_GRID).unwrap();
+
+        assert_eq!(grid.position_of(&'e'), Some(Position::new(1, 1)));
+        assert_eq!(grid.position_of(&'z'), None);
+
+        grid[Position::new(0, 0)] = 'e';
+
+        assert_eq!(
+            grid.positions_of(&'e').collect::<Vec<_>>(),
+            vec![Position::new(0, 0), Position::new(1, 1)]
+        );
+        assert_eq!(
+            grid.map(|&c| c == 'e').to_string(),
+            "truefalsefalse\nfalsetruefalse"
+        );
+    }
+}
@@ -0,0 +1,329 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    iter,
+    ops::Range,
+    path::Path,
+};
+
+use grid::Grid;
+
+use crate::{Cell, Frames};
+
+#[derive(Debug)]
+pub enum ExportError {
+    Io(io::Error),
+    Png(png::EncodingError),
+    Gif(gif::EncodingError),
+    TooLarge { width: usize, height: usize },
+}
+
+impl Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportError::Io(error) => write!(f, "{}", error),
+            ExportError::Png(error) => write!(f, "could not encode PNG: {}", error),
+            ExportError::Gif(error) => write!(f, "could not encode GIF: {}", error),
+            ExportError::TooLarge { width, height } => {
+                write!(f, "a {}x{} image is too large for a GIF", width, height)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+impl From<io::Error> for ExportError {
+    fn from(error: io::Error) -> Self {
+        ExportError::Io(error)
+    }
+}
+
+impl From<png::EncodingError> for ExportError {
+    fn from(error: png::EncodingError) -> Self {
+        ExportError::Png(error)
+    }
+}
+
+impl From<gif::EncodingError> for ExportError {
+    fn from(error: gif::EncodingError) -> Self {
+        ExportError::Gif(error)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+}
+
+impl ImageFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "ppm" => Some(ImageFormat::Ppm),
+            "png" => Some(ImageFormat::Png),
+            _ => None,
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+/// An RGB image of a grid, with each cell drawn as a `scale` by `scale` square of its colour.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<[u8; 3]>,
+}
+
+impl Image {
+    pub fn from_grid<C: Cell>(grid: &Grid<C>, scale: usize) -> Self {
+        let width = grid.width() * scale;
+        let height = grid.height() * scale;
+
+        let mut pixels = Vec::with_capacity(width * height);
+
+        for row in grid.rows() {
+            let row_pixels = row
+                .iter()
+                .flat_map(|cell| iter::repeat_n(cell.colour().rgb(), scale))
+                .collect::<Vec<_>>();
+
+            for _ in 0..scale {
+                pixels.extend_from_slice(&row_pixels);
+            }
+        }
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn write(&self, format: ImageFormat, writer: impl Write) -> Result<(), ExportError> {
+        match format {
+            ImageFormat::Ppm => self.write_ppm(writer),
+            ImageFormat::Png => self.write_png(writer),
+        }
+    }
+
+    pub fn save(&self, format: ImageFormat, path: &Path) -> Result<(), ExportError> {
+        self.write(format, BufWriter::new(File::create(path)?))
+    }
+
+    /// Writes a binary (P6) PPM.
+    pub fn write_ppm(&self, mut writer: impl Write) -> Result<(), ExportError> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+        writer.write_all(&self.pixels.concat())?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    pub fn write_png(&self, writer: impl Write) -> Result<(), ExportError> {
+        let mut encoder = png::Encoder::new(writer, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels.concat())?;
+        writer.finish()?;
+
+        Ok(())
+    }
+
+    fn gif_frame(&self) -> gif::Frame<'static> {
+        let (width, height) = (self.width as u16, self.height as u16);
+
+        let mut palette = HashMap::new();
+        let indices = self
+            .pixels
+            .iter()
+            .map(|pixel| {
+                let next_index = palette.len();
+                *palette.entry(*pixel).or_insert(next_index)
+            })
+            .collect::<Vec<_>>();
+
+        // Cells come in a handful of colours, so an exact palette almost always fits.
+        if palette.len() > 256 {
+            return gif::Frame::from_rgb_speed(width, height, &self.pixels.concat(), 10);
+        }
+
+        let mut colours = vec![[0; 3]; palette.len()];
+        for (colour, index) in palette {
+            colours[index] = colour;
+        }
+
+        gif::Frame::from_palette_pixels(
+            width,
+            height,
+            indices
+                .into_iter()
+                .map(|index| index as u8)
+                .collect::<Vec<_>>(),
+            colours.concat(),
+            None,
+        )
+    }
+}
+
+/// Writes the frames in `range` as a looping GIF, showing each for `delay` hundredths of a second.
+pub fn write_gif<F: Frames>(
+    frames: &mut F,
+    range: Range<usize>,
+    scale: usize,
+    delay: u16,
+    writer: impl Write,
+) -> Result<(), ExportError> {
+    let mut indices = range;
+    let Some(first_index) = indices.next() else {
+        return Ok(());
+    };
+
+    let first_image = Image::from_grid(&frames.frame(first_index), scale);
+
+    let (Ok(width), Ok(height)) = (
+        u16::try_from(first_image.width),
+        u16::try_from(first_image.height),
+    ) else {
+        return Err(ExportError::TooLarge {
+            width: first_image.width,
+            height: first_image.height,
+        });
+    };
+
+    let mut encoder = gif::Encoder::new(BufWriter::new(writer), width, height, &[])?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+
+    let images = iter::once(first_image)
+        .chain(indices.map(|index| Image::from_grid(&frames.frame(index), scale)));
+
+    for image in images {
+        let mut frame = image.gif_frame();
+        frame.delay = delay;
+        encoder.write_frame(&frame)?;
+    }
+
+    encoder.into_inner()?.flush()?;
+
+    Ok(())
+}
+
+/// Writes the frames in `range` to `directory` as `frame-00000.png` and so on, creating it if
+/// needed.
+pub fn write_frame_directory<F: Frames>(
+    frames: &mut F,
+    range: Range<usize>,
+    scale: usize,
+    format: ImageFormat,
+    directory: &Path,
+) -> Result<(), ExportError> {
+    fs::create_dir_all(directory)?;
+
+    for index in range {
+        let path = directory.join(format!("frame-{:05}.{}", index, format.extension()));
+
+        Image::from_grid(&frames.frame(index), scale).save(format, &path)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Colour;
+
+    #[derive(Clone, Copy)]
+    struct Lit(bool);
+
+    impl Cell for Lit {
+        fn symbol(&self) -> char {
+            if self.0 {
+                '#'
+            } else {
+                '.'
+            }
+        }
+
+        fn colour(&self) -> Colour {
+            if self.0 {
+                Colour::Rgb(255, 0, 0)
+            } else {
+                Colour::Default
+            }
+        }
+    }
+
+    #[test]
+    fn write_ppm_works() {
+        let grid = Grid::parse_with("#.", |character| Some(Lit(character == '#'))).unwrap();
+
+        let mut output = Vec::new();
+        Image::from_grid(&grid, 2).write_ppm(&mut output).unwrap();
+
+        let mut expected = b"P6\n4 2\n255\n".to_vec();
+        for _ in 0..2 {
+            expected.extend([255, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0]);
+        }
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn write_png_works() {
+        let grid = Grid::parse_with("#.\n.#", |character| Some(Lit(character == '#'))).unwrap();
+
+        let mut output = Vec::new();
+        Image::from_grid(&grid, 3).write_png(&mut output).unwrap();
+
+        let mut reader = png::Decoder::new(output.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+
+        assert_eq!((info.width, info.height), (6, 6));
+        assert_eq!(&pixels[..3], &[255, 0, 0]);
+        assert_eq!(&pixels[9..12], &[0, 0, 0]);
+    }
+
+    struct Blink;
+
+    impl Frames for Blink {
+        type Cell = Lit;
+
+        fn frame_count(&self) -> usize {
+            2
+        }
+
+        fn frame(&mut self, index: usize) -> Grid<Self::Cell> {
+            Grid::new(2, 1, Lit(index.is_multiple_of(2)))
+        }
+    }
+
+    #[test]
+    fn write_gif_works() {
+        let mut output = Vec::new();
+        write_gif(&mut Blink, 0..2, 1, 50, &mut output).unwrap();
+
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(output.as_slice())
+            .unwrap();
+        let mut delays = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            delays.push(frame.delay);
+        }
+
+        assert_eq!((decoder.width(), decoder.height()), (2, 1));
+        assert_eq!(delays, vec![50, 50]);
+    }
+}
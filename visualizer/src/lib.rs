@@ -0,0 +1,141 @@
+mod export;
+mod player;
+
+use grid::Grid;
+
+pub use export::{write_frame_directory, write_gif, ExportError, Image, ImageFormat};
+pub use player::{parse_speed, play, Options};
+
+/// A foreground colour from the basic ANSI palette, or an exact one for terminals with true colour
+/// support. In images, [`Colour::Default`] is the black background.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Colour {
+    Default,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+    Rgb(u8, u8, u8),
+}
+
+impl Colour {
+    fn ansi_escape(&self) -> String {
+        let code = match self {
+            Colour::Default => 39,
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+            Colour::White => 37,
+            Colour::Grey => 90,
+            Colour::Rgb(red, green, blue) => {
+                return format!("\x1b[38;2;{};{};{}m", red, green, blue);
+            }
+        };
+
+        format!("\x1b[{}m", code)
+    }
+
+    pub fn rgb(&self) -> [u8; 3] {
+        match self {
+            Colour::Default => [0, 0, 0],
+            Colour::Red => [220, 50, 47],
+            Colour::Green => [80, 200, 80],
+            Colour::Yellow => [230, 200, 40],
+            Colour::Blue => [50, 100, 220],
+            Colour::Magenta => [200, 60, 200],
+            Colour::Cyan => [40, 190, 200],
+            Colour::White => [230, 230, 230],
+            Colour::Grey => [70, 70, 70],
+            Colour::Rgb(red, green, blue) => [*red, *green, *blue],
+        }
+    }
+}
+
+/// A kind of grid cell, drawn as a single coloured character.
+pub trait Cell {
+    fn symbol(&self) -> char;
+
+    fn colour(&self) -> Colour;
+}
+
+/// The states of a simulation, rendered one grid at a time.
+pub trait Frames {
+    type Cell: Cell;
+
+    fn frame_count(&self) -> usize;
+
+    /// Returns the grid for frame `index`, which is less than [`Frames::frame_count`].
+    fn frame(&mut self, index: usize) -> Grid<Self::Cell>;
+}
+
+/// Renders `grid` as lines of ANSI-coloured characters, switching colour only where it changes.
+pub fn render<C: Cell>(grid: &Grid<C>) -> String {
+    let mut output = String::new();
+
+    for row in grid.rows() {
+        let mut current_colour = Colour::Default;
+
+        for cell in row {
+            if cell.colour() != current_colour {
+                current_colour = cell.colour();
+                output.push_str(&current_colour.ansi_escape());
+            }
+
+            output.push(cell.symbol());
+        }
+
+        if current_colour != Colour::Default {
+            output.push_str(&Colour::Default.ansi_escape());
+        }
+
+        output.push('\n');
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Clone, Copy)]
+    enum Tile {
+        Wall,
+        Empty,
+    }
+
+    impl Cell for Tile {
+        fn symbol(&self) -> char {
+            match self {
+                Tile::Wall => '#',
+                Tile::Empty => '.',
+            }
+        }
+
+        fn colour(&self) -> Colour {
+            match self {
+                Tile::Wall => Colour::White,
+                Tile::Empty => Colour::Default,
+            }
+        }
+    }
+
+    #[test]
+    fn render_works() {
+        let grid = Grid::parse_with("##.\n...", |character| match character {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Empty),
+            _ => None,
+        })
+        .unwrap();
+
+        assert_eq!(render(&grid), "\x1b[37m##\x1b[39m.\n...\n");
+    }
+}
@@ -0,0 +1,240 @@
+use std::{
+    io::{self, Write},
+    thread,
+    time::{Duration, Instant},
+};
+
+use termion::{
+    async_stdin, clear, cursor, event::Key, input::TermRead, raw::IntoRawMode,
+    screen::IntoAlternateScreen,
+};
+
+use crate::{render, Frames};
+
+const MIN_SPEED: f64 = 0.25;
+const MAX_SPEED: f64 = 1024.0;
+
+const HELP: &str = "space play/pause  ←/→ previous/next  g/G first/last  +/- speed  q quit";
+
+pub struct Options {
+    /// Shown above the frame.
+    pub title: String,
+    /// Frames per second while playing.
+    pub speed: f64,
+}
+
+/// Parses a number of frames per second, which must be positive, such as `10` or `0.5`. Playback
+/// is then held between the slowest and fastest speeds the player supports.
+pub fn parse_speed(speed: &str) -> Result<f64, String> {
+    let speed = speed
+        .parse::<f64>()
+        .map_err(|error| format!("{}: expected a number of frames per second", error))?;
+
+    if !speed.is_finite() || speed <= 0.0 {
+        return Err(format!(
+            "expected a positive number of frames per second, found {}",
+            speed
+        ));
+    }
+
+    Ok(speed)
+}
+
+#[derive(Debug, PartialEq)]
+enum Control {
+    Continue,
+    Quit,
+}
+
+/// Which frame is showing and how playback is set, independent of the terminal.
+#[derive(Clone, Debug, PartialEq)]
+struct Player {
+    index: usize,
+    frame_count: usize,
+    playing: bool,
+    speed: f64,
+}
+
+impl Player {
+    fn new(frame_count: usize, speed: f64) -> Self {
+        Self {
+            index: 0,
+            frame_count,
+            playing: false,
+            speed: speed.clamp(MIN_SPEED, MAX_SPEED),
+        }
+    }
+
+    fn handle_key(&mut self, key: Key) -> Control {
+        match key {
+            Key::Char(' ') => self.playing = !self.playing,
+            Key::Right | Key::Char('l') | Key::Char('n') => {
+                self.playing = false;
+                self.next();
+            }
+            Key::Left | Key::Char('h') | Key::Char('p') => {
+                self.playing = false;
+                self.index = self.index.saturating_sub(1);
+            }
+            Key::Home | Key::Char('g') => self.index = 0,
+            Key::End | Key::Char('G') => self.index = self.frame_count - 1,
+            Key::Char('+') | Key::Char('=') => self.speed = (self.speed * 2.0).min(MAX_SPEED),
+            Key::Char('-') => self.speed = (self.speed / 2.0).max(MIN_SPEED),
+            Key::Char('q') | Key::Esc | Key::Ctrl('c') => return Control::Quit,
+            _ => {}
+        }
+
+        Control::Continue
+    }
+
+    /// Moves to the next frame while playing, pausing on the last one.
+    fn tick(&mut self) {
+        if self.playing && !self.next() {
+            self.playing = false;
+        }
+    }
+
+    fn next(&mut self) -> bool {
+        if self.index + 1 < self.frame_count {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn frame_interval(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.speed)
+    }
+}
+
+/// Shows `frames` in the terminal's alternate screen until the user quits, starting paused on
+/// the first frame.
+pub fn play<F: Frames>(mut frames: F, options: &Options) -> io::Result<()> {
+    if frames.frame_count() == 0 {
+        return Ok(());
+    }
+
+    if !termion::is_tty(&io::stdout()) {
+        return Err(io::Error::other(
+            "the visualizer needs to run in a terminal",
+        ));
+    }
+
+    let mut screen = io::stdout().into_raw_mode()?.into_alternate_screen()?;
+    write!(screen, "{}", cursor::Hide)?;
+
+    let result = run(&mut screen, &mut frames, options);
+
+    write!(screen, "{}", cursor::Show)?;
+    screen.flush()?;
+
+    result
+}
+
+fn run<F: Frames>(screen: &mut impl Write, frames: &mut F, options: &Options) -> io::Result<()> {
+    let mut keys = async_stdin().keys();
+    let mut player = Player::new(frames.frame_count(), options.speed);
+    let mut drawn = None;
+    let mut last_tick = Instant::now();
+
+    loop {
+        if drawn.as_ref() != Some(&player) {
+            draw(screen, frames, &player, options)?;
+            drawn = Some(player.clone());
+        }
+
+        for key in keys.by_ref() {
+            if player.handle_key(key?) == Control::Quit {
+                return Ok(());
+            }
+        }
+
+        if player.playing && last_tick.elapsed() >= player.frame_interval() {
+            player.tick();
+            last_tick = Instant::now();
+        }
+
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+fn draw<F: Frames>(
+    screen: &mut impl Write,
+    frames: &mut F,
+    player: &Player,
+    options: &Options,
+) -> io::Result<()> {
+    let frame = render(&frames.frame(player.index));
+
+    write!(
+        screen,
+        "{}{}{}  frame {}/{}  {} at {} fps\r\n{}\r\n\r\n{}",
+        clear::All,
+        cursor::Goto(1, 1),
+        options.title,
+        player.index + 1,
+        player.frame_count,
+        if player.playing { "playing" } else { "paused" },
+        player.speed,
+        HELP,
+        frame.replace('\n', "\r\n")
+    )?;
+
+    screen.flush()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn player_handle_key_works() {
+        let mut player = Player::new(3, 8.0);
+
+        player.handle_key(Key::Left);
+        assert_eq!(player.index, 0);
+
+        player.handle_key(Key::Right);
+        player.handle_key(Key::Char('n'));
+        player.handle_key(Key::Right);
+        assert_eq!(player.index, 2);
+
+        player.handle_key(Key::Char('p'));
+        assert_eq!(player.index, 1);
+
+        player.handle_key(Key::Char('+'));
+        assert_eq!(player.speed, 16.0);
+        player.handle_key(Key::Char('-'));
+        player.handle_key(Key::Char('-'));
+        assert_eq!(player.speed, 4.0);
+
+        assert_eq!(player.handle_key(Key::Char('q')), Control::Quit);
+    }
+
+    #[test]
+    fn player_tick_works() {
+        let mut player = Player::new(2, 8.0);
+
+        player.tick();
+        assert_eq!(player.index, 0);
+
+        player.handle_key(Key::Char(' '));
+        player.tick();
+        assert_eq!((player.index, player.playing), (1, true));
+
+        player.tick();
+        assert_eq!((player.index, player.playing), (1, false));
+    }
+
+    #[test]
+    fn parse_speed_works() {
+        assert_eq!(parse_speed("10"), Ok(10.0));
+        assert_eq!(parse_speed("0.5"), Ok(0.5));
+        assert!(parse_speed("nan").is_err());
+        assert!(parse_speed("inf").is_err());
+        assert!(parse_speed("0").is_err());
+        assert!(parse_speed("-2").is_err());
+        assert!(parse_speed("fast").is_err());
+    }
+}